@@ -19,6 +19,11 @@
 //! assert!(a.eq_ignoring_meta(&b));
 //! ```
 //!
+//! Beyond `ignore(...)` and `method = "..."`, options choose the compared fields, change how single
+//! fields are compared and add further outputs, such as the list of differing fields or a `PartialEq`
+//! impl; the README describes each of them. `#[derive(SubsetEq)]` offers the same options, read from
+//! `#[subset(...)]` on the type, with fields skipped by `#[subset(ignore)]`.
+//!
 //! ### Teaching notes / rationale
 //! 1. Procedural macros must live in their own crate with `proc-macro = true` because they are compiled for the host and produce code used in the consuming crate. :contentReference[oaicite:0]{index=0}  
 //! 2. We parse attribute arguments manually via the `Parse` trait to avoid brittle assumptions about internal AST shapes (e.g., avoiding direct reliance on legacy `MetaList.nested`). :contentReference[oaicite:1]{index=1}  
@@ -27,184 +32,4687 @@
 //! 5. Errors are surfaced early with spans using `syn::Error` so misuse shows clear compile-time diagnostics. :contentReference[oaicite:4]{index=4}  
 
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
+use proc_macro2::{Group, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::collections::HashSet;
 use syn::{
-    parse::{Parse, ParseStream},
-    parse_macro_input,
+    ext::IdentExt,
+    parse::{Parse, ParseStream, Parser},
+    parse_macro_input, parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
     token::Comma,
-    Data, DeriveInput, Error, Expr, Fields, Ident,
+    Attribute, Data, DeriveInput, Error, Expr, ExprCall, Field, Fields, GenericParam, Ident, Index,
+    Lit, LitFloat, LitInt, LitStr, Member, Path, Token, Type, Visibility, WherePredicate,
 };
 
 /// Parsed attribute arguments for `#[subset_eq(...)]`.
 /// Supported components (in any order):
-///   - `ignore(field1, field2)`
+///   - `ignore(field1, field2)` (or `ignore(0, 2)` for tuple structs)
+///   - `ignore(meta.field)` (delegate `meta` to its own `eq_subset_ignoring`)
+///   - `ignore(.., keep = (field1, field2))` (same as `only(field1, field2)`)
+///   - `only(field1, field2)` (mutually exclusive with `ignore`)
+///   - `ignore_prefix("cache_", "tmp_")` (ignore every field whose name starts with a prefix)
+///   - `preset = "created_at,updated_at"` (comma-separated fields ignored on top of `ignore(...)`)
+///   - `ignore_type("std::time::Instant")` (ignore every field of a type, as written)
+///   - `pub_only` (ignore every field that is not `pub`)
+///   - `auto_skip_phantom` (leave out `PhantomData` fields)
 ///   - `method = "custom_name"`
+///   - `as_trait` (implement `subset_eq_traits::SubsetEq`)
+///   - `impl_trait = "MyCompare"` (emit the main method in an impl of a user-defined trait)
+///   - `sealed_trait = "crate::cmp::SubsetCmp"` (like `impl_trait`, for a trait sealed with `sealed!`)
+///   - `module = "item_cmp"` (emit the main method as a free function in a generated module)
+///   - `variants(eq_a(field1), eq_b(field2, field3))` (one extra method per entry)
+///   - `group(header = (field1, field2))` (an `eq_<group>` method comparing only the group's fields)
+///   - `allow_group_overlap` (let a field belong to several groups)
+///   - `recurse(field1 = "eq_child")` (compare a field through its own subset method)
+///   - `with(field1 = "path::to::fn")` (compare a field via `fn(&T, &T) -> bool`)
+///   - `type_with("f64" = "path::to::fn")` (compare every field of a type via a function)
+///   - `key(field1 = |v| v.id())` (compare a field by a key extracted with a closure)
+///   - `project(field1 = "epoch_seconds")` (compare a field by the result of one of its methods)
+///   - `diff = "diff_fields"` (list the names of differing fields)
+///   - `no_std` (return fixed-size arrays instead of `Vec`s from the name-listing outputs)
+///   - `try_method = "check_eq"` (`Err` with the first differing field's name)
+///   - `log_mismatch` (log the first differing field of the main method via `log::debug!`)
+///   - `serde_names` (report fields by their `#[serde(rename = "...")]` name)
+///   - `other = "OtherType"` (compare the main method against another struct)
+///   - `ref_other = "ItemRef"` (a `matches_ref` method against a borrowed view, dereferencing its fields)
+///   - `approx(field1 = 1e-9)` (compare floats within an absolute tolerance)
+///   - `approx_const(field1 = EPS)` (like `approx`, with the tolerance taken from a constant)
+///   - `total(field1)` (compare float fields with `total_cmp`, so identical NaNs are equal)
+///   - `array_ignore(field1 = [0, 7])` (compare an array field without the listed indices)
+///   - `map_ignore_keys(field1 = ["nonce"])` (compare a map field without the listed keys)
+///   - `wildcard_none(field1)` (an `Option` field that is `None` on either side matches)
+///   - `default_wildcard(field1)` (a field at its `Default` value on either side matches)
+///   - `skip_if_both("str::is_empty", fields = (field1))` (skip a field when a predicate holds on both sides)
+///   - `deref(field1)` (compare what a smart-pointer field points to)
+///   - `by_ptr(field1)` (compare a pointer field by the address it points to)
+///   - `case_insensitive(field1)` (compare text fields ignoring ASCII case)
+///   - `unordered(field1)` (compare collections regardless of element order)
+///   - `by_display(field1)` (compare fields by their `Display` output)
+///   - `normalize(field1 = "str::trim")` (compare fields after normalizing both sides)
+///   - `compare_order(field1, field2)` (check these fields first, for faster short-circuiting)
+///   - `ne_method = "ne_name"` (negation of the main method)
+///   - `full_method = "eq_all"` (a second method comparing every field)
+///   - `slice_method = "slices_eq"` (element-wise comparison of two slices)
+///   - `vis = "pub(crate)"` (visibility of the generated methods, `pub` by default)
+///   - `report = "ItemDiff"` (generate a per-field change report struct and `subset_diff`)
+///   - `first_diff = "first_diff"` with `diff_enum = "ItemFieldDiff"` (the first differing field and
+///     both of its values, as a generated enum)
+///   - `const_fn` (emit the equality methods as `const fn`)
+///   - `flexible_rhs` (the main method takes any `B: Borrow<Self>`)
+///   - `runtime_mask` (the main method takes a bit mask of fields to skip)
+///   - `conditional(field1, field2)` (the main method takes an `ignore_<field>: bool` per field)
+///   - `by_value` (the main method takes `self` and `other` by value)
+///   - `bytewise` (the main method compares the raw bytes of a padding-free `#[repr(C)]` struct)
+///   - `allow_empty` (accept a struct without fields; its instances are always equal)
+///   - `strict` (reject a configuration that excludes no field at all)
+///   - `exhaustive` (an associated `SUBSET_EQ_FIELDS` constant naming the compared fields)
+///   - `reflect` (`SUBSET_EQ_COMPARED` / `SUBSET_EQ_IGNORED` constants naming both field sets)
+///   - `score = "similarity"` (fraction of compared fields that are equal)
+///   - `field_eq_iter = "field_equality"` (iterate `(field name, equal)` pairs)
+///   - `invert` (compare only the ignored fields instead)
+///   - `changed_method = "meta_changed"` (`true` when any ignored field differs)
+///   - `fn = "eq_fn"` (a free function `fn(&T, &T) -> bool` next to the struct)
+///   - `gen_macro` (a `<struct>_matches!(a, b)` shorthand for the main method)
+///   - `gen_proptest` (associated functions checking reflexivity, symmetry and transitivity)
+///   - `ord_method = "cmp_stable"` (lexicographic `Ordering` over the compared fields)
+///   - `cmp_key_method = "sort_key"` (an owned tuple of the compared fields, for sorting)
+///   - `hash_method = "hash_subset"` (feed the compared fields to a `Hasher`)
+///   - `profile_method = "eq_with"` (compare float fields through a `ComparisonProfile` type parameter)
+///   - `key_wrapper = "ItemKey"` (a newtype with `Eq` and `Hash` over the subset, for map keys)
+///   - `fold_method = "fold_fields"` (fold over the compared fields as `&dyn Debug`)
+///   - `method_suffix = "_v2"` (appended to every generated method and function name)
+///   - `attrs("inline", "must_use")` (extra attributes on the generated equality functions)
+///   - `derive_partial_eq` / `derive_eq` (implement `PartialEq` / `Eq` from the subset)
+///   - `derive_partial_ord` (implement `PartialOrd` from the subset)
+///   - `debug_print` (print the expansion while compiling)
+///   - `check_bounds` (assert `PartialEq` per field so errors point at the field type)
+///   - `where_bound = "T: MyTrait"` (extra predicates for the generated impls)
+///   - `unsafe_union(field1)` (compare these fields of a union, in an `unsafe` block)
+#[derive(Default)]
 struct Args {
-    ignored: Vec<Ident>,
+    /// Every option name in the order given, for diagnostics about unsupported combinations.
+    used: Vec<Ident>,
+    ignored: Vec<Member>,
+    /// Fields named as the root of a dotted path in `ignore(...)`, compared via their own
+    /// subset method.
+    nested: Vec<Member>,
+    ignore_prefix: Vec<String>,
+    /// Field types whose fields are all ignored, matched on the written type.
+    ignore_type: Vec<Type>,
+    /// Ignore every field whose visibility is not plain `pub`.
+    pub_only: bool,
+    /// Leave out fields whose type is written as `PhantomData`.
+    auto_skip_phantom: bool,
+    only: Option<Vec<Member>>,
     method: Option<Ident>,
+    as_trait: bool,
+    /// User trait the main method is implemented for, instead of an inherent impl.
+    impl_trait: Option<Path>,
+    /// Sealed user trait receiving the main method, handled like `impl_trait`.
+    sealed_trait: Option<Path>,
+    /// Module holding the main method as a free function instead of the inherent impl.
+    module: Option<Ident>,
+    /// Additional `(method, ignored fields)` pairs generated alongside the main method.
+    variants: Vec<(Ident, Vec<Member>)>,
+    /// Named field groups, each compared by its own `eq_<group>` method.
+    groups: Vec<(Ident, Vec<Member>)>,
+    /// Allow a field to be listed in more than one group.
+    allow_group_overlap: bool,
+    /// Avoid `std` in the generated code, returning arrays where a `Vec` would be used.
+    no_std: bool,
+    /// Per-field comparison options, each field getting one rule.
+    rules: RuleArgs,
+    /// Fields checked first in the equality chain, in the given order.
+    compare_order: Vec<Member>,
+    /// Name of the generated method listing the differing fields.
+    diff: Option<Ident>,
+    /// Name of the generated method returning the first differing field as an `Err`.
+    try_method: Option<Ident>,
+    /// Log the first differing field of the main method through `log::debug!`.
+    log_mismatch: bool,
+    /// Report fields by their `#[serde(rename = "...")]` name where present.
+    serde_names: bool,
+    /// Right-hand side type of the main method when comparing against another struct.
+    other: Option<Type>,
+    /// Borrowed view type compared by `matches_ref`, whose fields are dereferenced.
+    ref_other: Option<Type>,
+    /// Name of the generated negation of the main method.
+    ne_method: Option<Ident>,
+    /// Name of the generated method comparing every field, ignored ones included.
+    full_method: Option<Ident>,
+    /// Name of the generated associated function comparing slices element-wise.
+    slice_method: Option<Ident>,
+    /// Visibility of the generated inherent methods.
+    vis: Option<Visibility>,
+    /// Name of the generated per-field change report struct.
+    report: Option<Ident>,
+    /// Name of the generated method returning the first differing field with both values.
+    first_diff: Option<Ident>,
+    /// Name of the generated enum holding that field's values, one variant per compared field.
+    diff_enum: Option<Ident>,
+    const_fn: bool,
+    flexible_rhs: bool,
+    /// Give the main method an `ignore_mask: u64` parameter and emit per-field bit constants.
+    runtime_mask: bool,
+    /// Fields the main method skips while their `ignore_<field>: bool` parameter is `true`.
+    conditional: Vec<Member>,
+    /// Take `self` and `other` by value in the main method and its negation.
+    by_value: bool,
+    /// Compare the raw bytes of the whole value in the main method.
+    bytewise: bool,
+    /// Emit a `PartialEq` assertion per compared field type.
+    check_bounds: bool,
+    allow_empty: bool,
+    /// Reject a configuration under which every field is compared.
+    strict: bool,
+    /// Emit the associated `SUBSET_EQ_FIELDS` constant.
+    exhaustive: bool,
+    /// Emit the associated `SUBSET_EQ_COMPARED` and `SUBSET_EQ_IGNORED` constants.
+    reflect: bool,
+    /// Name of the generated similarity score method.
+    score: Option<Ident>,
+    /// Name of the generated method yielding `(field, equal)` pairs.
+    field_eq_iter: Option<Ident>,
+    /// Compare exactly the ignored fields and skip the rest.
+    invert: bool,
+    /// Name of the generated free function taking both values by reference.
+    free_fn: Option<Ident>,
+    /// Emit a `<struct>_matches!` macro calling the main method.
+    gen_macro: bool,
+    /// Emit the equivalence-law helpers for property tests.
+    gen_proptest: bool,
+    /// Name of the generated `Ordering` method over the compared fields.
+    ord_method: Option<Ident>,
+    /// Name of the generated method returning the compared fields as an owned tuple.
+    cmp_key_method: Option<Ident>,
+    /// Name of the generated method hashing the compared fields.
+    hash_method: Option<Ident>,
+    /// Name of the generated method comparing float fields through a `ComparisonProfile`.
+    profile_method: Option<Ident>,
+    /// Name of the generated newtype implementing `Eq` and `Hash` over the compared fields.
+    key_wrapper: Option<Ident>,
+    /// Name of the generated method folding over the compared fields as `&dyn Debug`.
+    fold_method: Option<Ident>,
+    /// Name of the generated method telling whether any ignored field differs.
+    changed_method: Option<Ident>,
+    /// Appended to every generated method and function name; empty when not given.
+    method_suffix: String,
+    /// Attributes added to the generated equality methods and function.
+    attrs: Vec<syn::Meta>,
+    /// Implement `PartialEq` itself from the subset comparison.
+    derive_partial_eq: bool,
+    derive_partial_ord: bool,
+    /// Also implement `Eq`, requiring it of every compared field.
+    derive_eq: bool,
+    /// Print the generated code at expansion time.
+    debug_print: bool,
+    /// Union fields to compare, read without knowing which one is active.
+    unsafe_union: Option<Vec<Member>>,
+    /// Extra predicates appended to the where-clause of every generated impl and function.
+    where_bound: Vec<WherePredicate>,
+}
+
+/// The options giving fields a way of comparing other than `==`.
+#[derive(Default)]
+struct RuleArgs {
+    /// Fields compared through a custom function instead of `==`.
+    with: Vec<(Member, Path)>,
+    /// Fields compared through their own subset method, with that method's name.
+    recurse: Vec<(Member, Ident)>,
+    /// Float fields compared within an absolute tolerance.
+    approx: Vec<(Member, LitFloat)>,
+    /// Float fields compared within the tolerance held by a constant.
+    approx_const: Vec<(Member, Path)>,
+    /// Array fields compared element by element, skipping the listed indices.
+    array_ignore: Vec<(Member, Vec<LitInt>)>,
+    /// Map fields compared after removing the listed keys from both sides.
+    map_ignore_keys: Vec<(Member, Vec<Lit>)>,
+    /// Fields compared by the closure's result instead of by the field itself.
+    key: Vec<(Member, syn::ExprClosure)>,
+    /// Fields compared by the result of a named method called on both sides.
+    project: Vec<(Member, Ident)>,
+    /// Comparators applied to every compared field of a type, unless the field has its own rule.
+    type_with: Vec<(Type, Path)>,
+    /// `Option` fields where `None` on either side matches anything.
+    wildcard_none: Vec<Member>,
+    /// Fields where the `Default` value on either side matches anything.
+    default_wildcard: Vec<Member>,
+    /// Predicates with the fields they skip when the predicate holds for both sides.
+    skip_if_both: Vec<(Path, Vec<Member>)>,
+    /// Smart-pointer fields compared by the value they point to.
+    deref: Vec<Member>,
+    /// Pointer fields compared by the address they point to.
+    by_ptr: Vec<Member>,
+    /// Text fields compared with `eq_ignore_ascii_case`.
+    case_insensitive: Vec<Member>,
+    /// Collection fields compared as multisets of `Ord` elements.
+    unordered: Vec<Member>,
+    /// Fields compared by their `Display` output.
+    by_display: Vec<Member>,
+    /// Float fields compared with `total_cmp` instead of `==`.
+    total: Vec<Member>,
+    /// Fields compared after passing both sides through a normalizing function.
+    normalize: Vec<(Member, Path)>,
 }
 
+/// Accessor for the argument an option sets, as listed in the option tables below.
+type Slot<T> = fn(&mut Args) -> &mut T;
+
+/// Bare flags such as `as_trait`, with the argument each one switches on.
+const FLAGS: &[(&str, Slot<bool>)] = &[
+    ("as_trait", |args| &mut args.as_trait),
+    ("const_fn", |args| &mut args.const_fn),
+    ("flexible_rhs", |args| &mut args.flexible_rhs),
+    ("allow_empty", |args| &mut args.allow_empty),
+    ("strict", |args| &mut args.strict),
+    ("reflect", |args| &mut args.reflect),
+    ("exhaustive", |args| &mut args.exhaustive),
+    ("gen_proptest", |args| &mut args.gen_proptest),
+    ("gen_macro", |args| &mut args.gen_macro),
+    ("check_bounds", |args| &mut args.check_bounds),
+    ("auto_skip_phantom", |args| &mut args.auto_skip_phantom),
+    ("pub_only", |args| &mut args.pub_only),
+    ("runtime_mask", |args| &mut args.runtime_mask),
+    ("serde_names", |args| &mut args.serde_names),
+    ("log_mismatch", |args| &mut args.log_mismatch),
+    ("no_std", |args| &mut args.no_std),
+    ("allow_group_overlap", |args| &mut args.allow_group_overlap),
+    ("bytewise", |args| &mut args.bytewise),
+    ("by_value", |args| &mut args.by_value),
+    ("invert", |args| &mut args.invert),
+    ("derive_partial_ord", |args| &mut args.derive_partial_ord),
+    ("derive_partial_eq", |args| &mut args.derive_partial_eq),
+    ("derive_eq", |args| &mut args.derive_eq),
+    ("debug_print", |args| &mut args.debug_print),
+];
+
+/// Options naming a generated item, such as `method = "name"`. The flag marks the methods and
+/// functions, which get `method_suffix` appended; generated types and modules keep their name.
+const NAMES: &[(&str, bool, Slot<Option<Ident>>)] = &[
+    ("method", true, |args| &mut args.method),
+    ("module", false, |args| &mut args.module),
+    ("diff", true, |args| &mut args.diff),
+    ("try_method", true, |args| &mut args.try_method),
+    ("ne_method", true, |args| &mut args.ne_method),
+    ("full_method", true, |args| &mut args.full_method),
+    ("slice_method", true, |args| &mut args.slice_method),
+    ("report", false, |args| &mut args.report),
+    ("first_diff", true, |args| &mut args.first_diff),
+    ("diff_enum", false, |args| &mut args.diff_enum),
+    ("score", true, |args| &mut args.score),
+    ("field_eq_iter", true, |args| &mut args.field_eq_iter),
+    ("ord_method", true, |args| &mut args.ord_method),
+    ("cmp_key_method", true, |args| &mut args.cmp_key_method),
+    ("hash_method", true, |args| &mut args.hash_method),
+    ("profile_method", true, |args| &mut args.profile_method),
+    ("key_wrapper", false, |args| &mut args.key_wrapper),
+    ("fold_method", true, |args| &mut args.fold_method),
+    ("changed_method", true, |args| &mut args.changed_method),
+    // `fn` is a keyword and never reaches the `key = value` arm; it is listed for the suffix.
+    ("fn", true, |args| &mut args.free_fn),
+];
+
+/// Options listing fields that all get the same treatment, such as `deref(a, b)`.
+const MEMBER_LISTS: &[(&str, Slot<Vec<Member>>)] = &[
+    ("wildcard_none", |args| &mut args.rules.wildcard_none),
+    ("default_wildcard", |args| &mut args.rules.default_wildcard),
+    ("deref", |args| &mut args.rules.deref),
+    ("by_ptr", |args| &mut args.rules.by_ptr),
+    ("case_insensitive", |args| &mut args.rules.case_insensitive),
+    ("unordered", |args| &mut args.rules.unordered),
+    ("by_display", |args| &mut args.rules.by_display),
+    ("total", |args| &mut args.rules.total),
+    ("conditional", |args| &mut args.conditional),
+    ("compare_order", |args| &mut args.compare_order),
+];
+
 impl Parse for Args {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let mut ignored = Vec::new();
-        let mut method = None;
+        let mut args = Args::default();
+        // Names already listed in `ignore(...)`, to flag copy-paste duplicates.
+        let mut ignored_names = HashSet::new();
 
         // Flexible comma-separated list: allows `ignore(a,b), method = "x"` or reversed. :contentReference[oaicite:5]{index=5}
-        let items = Punctuated::<Expr, Comma>::parse_terminated(input)?;
+        // `fn` is a keyword, so `fn = "..."` is not an expression and is picked out by hand.
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            if input.peek(Token![fn]) {
+                let keyword = input.parse::<Token![fn]>()?;
+                input.parse::<Token![=]>()?;
+                args.used.push(Ident::new("fn", keyword.span));
+                args.free_fn = Some(parse_name(&input.parse()?, "fn")?);
+            } else {
+                items.push(input.parse::<Expr>()?);
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Comma>()?;
+        }
         for item in items {
             match item {
-                // Handles `ignore(a, b)`
+                // Handles list-style options such as `ignore(a, b)` and `only(a, b)`
                 Expr::Call(call) => {
-                    // Expect the function path to be `ignore`
-                    if let Expr::Path(func_path) = *call.func {
-                        if func_path.path.is_ident("ignore") {
-                            for arg in call.args.iter() {
-                                if let Expr::Path(p) = arg {
-                                    if let Some(id) = p.path.get_ident() {
-                                        ignored.push(id.clone());
-                                    } else {
-                                        return Err(Error::new(
-                                            p.span(),
-                                            "expected identifier in ignore(...)",
-                                        ));
-                                    }
-                                } else {
-                                    return Err(Error::new(
-                                        arg.span(),
-                                        "expected identifier in ignore(...)",
-                                    ));
-                                }
+                    let Expr::Path(func_path) = &*call.func else {
+                        return Err(Error::new(call.func.span(), "expected path in ignore(...)"));
+                    };
+                    let Some(name) = func_path.path.get_ident() else {
+                        return Err(Error::new(func_path.span(), "expected `ignore(...)`"));
+                    };
+                    args.used.push(name.clone());
+                    match name.to_string().as_str() {
+                        // `ignore(.., keep = (id, name))` is `only(id, name)` in ignore terms.
+                        "ignore" if call.args.iter().any(is_rest) => {
+                            if args.only.is_some()
+                                || !args.ignored.is_empty()
+                                || !args.nested.is_empty()
+                                || !args.ignore_prefix.is_empty()
+                                || !args.ignore_type.is_empty()
+                            {
+                                return Err(Error::new(
+                                    call.span(),
+                                    "`ignore(.., keep = (...))` cannot be combined with other `ignore(...)` or `only(...)` lists",
+                                ));
                             }
-                        } else {
-                            return Err(Error::new(func_path.span(), "expected `ignore(...)`"));
+                            args.only = Some(parse_keep(&call)?);
                         }
-                    } else {
-                        return Err(Error::new(call.func.span(), "expected path in ignore(...)"));
-                    }
-                }
-                // Handles `method = "name"`
-                Expr::Assign(assign) => {
-                    if let Expr::Path(lp) = *assign.left {
-                        if let Some(ident) = lp.path.get_ident() {
-                            if ident == "method" {
-                                if let Expr::Lit(el) = *assign.right {
-                                    if let syn::Lit::Str(ls) = el.lit {
-                                        method = Some(format_ident!("{}", ls.value()));
-                                    } else {
-                                        return Err(Error::new(
-                                            el.lit.span(),
-                                            "method value must be a string literal",
-                                        ));
+                        "ignore" => {
+                            if args.only.is_some() {
+                                return Err(Error::new(
+                                    call.span(),
+                                    "`ignore(...)` cannot be combined with `only(...)`",
+                                ));
+                            }
+                            for arg in &call.args {
+                                match arg {
+                                    // `meta.updated_at` targets a field of the nested struct.
+                                    Expr::Field(path) => args.nested.push(nested_root(path)?),
+                                    other => {
+                                        let member = parse_member(other, "ignore")?;
+                                        let name = member_name(&member);
+                                        if !ignored_names.insert(name.clone()) {
+                                            return Err(Error::new(
+                                                other.span(),
+                                                format!("field `{name}` listed more than once in ignore(...)"),
+                                            ));
+                                        }
+                                        args.ignored.push(member);
                                     }
-                                } else {
-                                    return Err(Error::new(
-                                        assign.right.span(),
-                                        "method value must be a string literal",
-                                    ));
                                 }
-                            } else {
+                            }
+                        }
+                        "ignore_prefix" => {
+                            if args.only.is_some() {
+                                return Err(Error::new(
+                                    call.span(),
+                                    "`ignore_prefix(...)` cannot be combined with `only(...)`",
+                                ));
+                            }
+                            args.ignore_prefix.extend(
+                                parse_str_list(&call, "ignore_prefix")?
+                                    .iter()
+                                    .map(LitStr::value),
+                            );
+                        }
+                        "ignore_type" => {
+                            if args.only.is_some() {
+                                return Err(Error::new(
+                                    call.span(),
+                                    "`ignore_type(...)` cannot be combined with `only(...)`",
+                                ));
+                            }
+                            for ty in parse_str_list(&call, "ignore_type")? {
+                                args.ignore_type.push(ty.parse::<Type>().map_err(|_| {
+                                    Error::new(
+                                        ty.span(),
+                                        format!("`{}` is not a valid type", ty.value()),
+                                    )
+                                })?);
+                            }
+                        }
+                        "only" => {
+                            if !args.ignored.is_empty()
+                                || !args.nested.is_empty()
+                                || !args.ignore_prefix.is_empty()
+                                || !args.ignore_type.is_empty()
+                            {
                                 return Err(Error::new(
-                                    ident.span(),
-                                    "expected `method` on left-hand side",
+                                    call.span(),
+                                    "`only(...)` cannot be combined with `ignore(...)`",
                                 ));
                             }
-                        } else {
+                            args.only
+                                .get_or_insert_with(Vec::new)
+                                .extend(parse_member_list(&call, "only")?);
+                        }
+                        "variants" => args.variants.extend(parse_variants(&call)?),
+                        "group" => args.groups.extend(parse_groups(&call)?),
+                        "with" => {
+                            args.rules
+                                .with
+                                .extend(parse_field_paths(&call, "with", "comparator")?)
+                        }
+                        "normalize" => args.rules.normalize.extend(parse_field_paths(
+                            &call,
+                            "normalize",
+                            "normalizer",
+                        )?),
+                        "recurse" => args.rules.recurse.extend(parse_recurse(&call)?),
+                        "approx" => args.rules.approx.extend(parse_approx(&call)?),
+                        "approx_const" => {
+                            args.rules.approx_const.extend(parse_approx_const(&call)?)
+                        }
+                        "array_ignore" => {
+                            args.rules.array_ignore.extend(parse_array_ignore(&call)?)
+                        }
+                        "map_ignore_keys" => args
+                            .rules
+                            .map_ignore_keys
+                            .extend(parse_map_ignore_keys(&call)?),
+                        "key" => args.rules.key.extend(parse_key(&call)?),
+                        "project" => args.rules.project.extend(parse_project(&call)?),
+                        "type_with" => args.rules.type_with.extend(parse_type_with(&call)?),
+                        "skip_if_both" => args.rules.skip_if_both.push(parse_skip_if_both(&call)?),
+                        "unsafe_union" => args
+                            .unsafe_union
+                            .get_or_insert_with(Vec::new)
+                            .extend(parse_member_list(&call, "unsafe_union")?),
+                        "attrs" => {
+                            for attr in parse_str_list(&call, "attrs")? {
+                                let meta = attr.parse::<syn::Meta>().map_err(|err| {
+                                    Error::new(
+                                        attr.span(),
+                                        format!("malformed attribute `{}`: {err}", attr.value()),
+                                    )
+                                })?;
+                                args.attrs.push(meta);
+                            }
+                        }
+                        "cfg_attr" | "cfg" => {
                             return Err(Error::new(
-                                lp.span(),
-                                "expected identifier on left-hand side",
+                                name.span(),
+                                format!(
+                                    "`{name}(...)` is not an option; wrap the whole attribute instead, e.g. `#[cfg_attr(test, subset_eq(...))]`"
+                                ),
                             ));
                         }
-                    } else {
+                        option => {
+                            let Some((_, list)) = MEMBER_LISTS.iter().find(|(n, _)| *n == option)
+                            else {
+                                return Err(Error::new(
+                                    name.span(),
+                                    format!("unsupported option `{name}(...)`"),
+                                ));
+                            };
+                            list(&mut args).extend(parse_member_list(&call, option)?);
+                        }
+                    }
+                }
+                // Handles named values such as `method = "name"`
+                Expr::Assign(assign) => {
+                    let Expr::Path(lp) = &*assign.left else {
                         return Err(Error::new(
                             assign.left.span(),
                             "expected `method = \"...\"` syntax",
                         ));
+                    };
+                    let Some(key) = lp.path.get_ident() else {
+                        return Err(Error::new(
+                            lp.span(),
+                            "expected identifier on left-hand side",
+                        ));
+                    };
+                    args.used.push(key.clone());
+                    let value = &assign.right;
+                    match key.to_string().as_str() {
+                        "impl_trait" => args.impl_trait = Some(parse_path(value, "impl_trait")?),
+                        "sealed_trait" => {
+                            args.sealed_trait = Some(parse_path(value, "sealed_trait")?)
+                        }
+                        "other" => args.other = Some(parse_type(value, "other")?),
+                        "preset" => {
+                            if args.only.is_some() {
+                                return Err(Error::new(
+                                    assign.span(),
+                                    "`preset` cannot be combined with `only(...)`",
+                                ));
+                            }
+                            for member in parse_preset(value)? {
+                                let name = member_name(&member);
+                                if !ignored_names.insert(name.clone()) {
+                                    return Err(Error::new(
+                                        value.span(),
+                                        format!(
+                                            "field `{name}` listed more than once in ignore(...)"
+                                        ),
+                                    ));
+                                }
+                                args.ignored.push(member);
+                            }
+                        }
+                        "ref_other" => args.ref_other = Some(parse_type(value, "ref_other")?),
+                        "vis" => args.vis = Some(parse_vis(value)?),
+                        "method_suffix" => args.method_suffix = parse_suffix(value)?,
+                        "where_bound" => args.where_bound.extend(parse_where_bound(value)?),
+                        option => {
+                            let Some((_, _, slot)) = NAMES.iter().find(|(n, ..)| *n == option)
+                            else {
+                                return Err(Error::new(
+                                    key.span(),
+                                    format!("unsupported option `{key} = ...`"),
+                                ));
+                            };
+                            *slot(&mut args) = Some(parse_name(value, option)?);
+                        }
                     }
                 }
+                // Handles bare flags such as `as_trait`
+                Expr::Path(flag) if FLAGS.iter().any(|(name, _)| flag.path.is_ident(name)) => {
+                    let (_, slot) = FLAGS
+                        .iter()
+                        .find(|(name, _)| flag.path.is_ident(name))
+                        .expect("checked by the guard");
+                    args.used.extend(flag.path.get_ident().cloned());
+                    *slot(&mut args) = true;
+                }
+                Expr::Path(flag) => {
+                    return Err(Error::new(
+                        flag.span(),
+                        format!("unsupported flag `{}`", flag.path.to_token_stream()),
+                    ));
+                }
                 other => {
                     return Err(Error::new(
                         other.span(),
-                        "unsupported argument; use `ignore(...)` or `method = \"...\"`",
+                        "unsupported argument; expected a flag, `option(...)` or `option = ...`",
                     ));
                 }
             }
         }
 
-        Ok(Args { ignored, method })
+        // Applied last so it also covers names given after `method_suffix`.
+        if !args.method_suffix.is_empty() {
+            let suffix = std::mem::take(&mut args.method_suffix);
+            for (_, suffixed, slot) in NAMES {
+                if let Some(name) = slot(&mut args).as_mut().filter(|_| *suffixed) {
+                    *name = format_ident!("{}{}", name, suffix, span = name.span());
+                }
+            }
+            for (name, _) in &mut args.variants {
+                *name = format_ident!("{}{}", name, suffix, span = name.span());
+            }
+            args.method_suffix = suffix;
+        }
+
+        Ok(args)
     }
 }
 
-/// The procedural attribute macro entry point.  
-/// Usage example:
-/// `#[subset_eq(ignore(updated_at), method = "eq_no_meta")]`
-#[proc_macro_attribute]
-pub fn subset_eq(attr: TokenStream, item: TokenStream) -> TokenStream {
-    // Parse the item the attribute is applied to (should be a struct).
-    let input = parse_macro_input!(item as DeriveInput);
-    // Parse our custom arguments.
-    let Args { ignored, method } = parse_macro_input!(attr as Args);
+/// Collects the fields passed to a list-style argument such as `ignore(a, b)`.
+fn parse_member_list(call: &ExprCall, name: &str) -> syn::Result<Vec<Member>> {
+    call.args
+        .iter()
+        .map(|arg| parse_member(arg, name))
+        .collect()
+}
 
-    // Determine generated method name, fallback if unspecified.
-    let method_name = method.unwrap_or_else(|| format_ident!("eq_subset_ignoring"));
-    let struct_name = &input.ident;
+/// Reports whether an argument is the bare `..` of `ignore(.., keep = (...))`.
+fn is_rest(arg: &Expr) -> bool {
+    matches!(arg, Expr::Range(range) if range.start.is_none() && range.end.is_none())
+}
 
-    // Collect all named fields that are not ignored.
-    let fields_to_compare = match &input.data {
-        Data::Struct(ds) => match &ds.fields {
-            Fields::Named(named) => named
-                .named
-                .iter()
-                .filter_map(|f| {
-                    let id = f.ident.as_ref().unwrap();
-                    if ignored.iter().any(|x| x == id) {
-                        None
-                    } else {
-                        Some(id.clone())
-                    }
-                })
-                .collect::<Vec<_>>(),
+/// Parses the fields kept by `ignore(.., keep = (id, name))`.
+fn parse_keep(call: &ExprCall) -> syn::Result<Vec<Member>> {
+    let mut keep = None;
+    for arg in &call.args {
+        match arg {
+            arg if is_rest(arg) => {}
+            Expr::Assign(assign) if matches!(&*assign.left, Expr::Path(p) if p.path.is_ident("keep")) =>
+            {
+                let fields = match &*assign.right {
+                    Expr::Tuple(tuple) => tuple.elems.iter().collect::<Vec<_>>(),
+                    Expr::Paren(paren) => vec![&*paren.expr],
+                    other => vec![other],
+                };
+                keep.get_or_insert_with(Vec::new).extend(
+                    fields
+                        .into_iter()
+                        .map(|field| parse_member(field, "keep"))
+                        .collect::<syn::Result<Vec<_>>>()?,
+                );
+            }
             other => {
-                return Error::new(
+                return Err(Error::new(
                     other.span(),
-                    "subset_eq only supports structs with named fields",
-                )
-                .to_compile_error()
-                .into();
+                    "`..` in `ignore(...)` cannot be combined with listed fields; name the fields to compare in `keep = (...)`",
+                ));
             }
+        }
+    }
+    keep.ok_or_else(|| {
+        Error::new(
+            call.span(),
+            "`ignore(..)` needs `keep = (...)` naming the fields to compare",
+        )
+    })
+}
+
+/// Collects the string literals passed to a list-style argument such as `ignore_prefix("a_")`.
+fn parse_str_list(call: &ExprCall, name: &str) -> syn::Result<Vec<LitStr>> {
+    call.args
+        .iter()
+        .map(|arg| match arg {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(ls), ..
+            }) => Ok(ls.clone()),
+            other => Err(Error::new(
+                other.span(),
+                format!("expected string literal in {name}(...)"),
+            )),
+        })
+        .collect()
+}
+
+/// Splits `preset = "created_at,updated_at"` into the fields it names.
+fn parse_preset(value: &Expr) -> syn::Result<Vec<Member>> {
+    let Expr::Lit(syn::ExprLit {
+        lit: Lit::Str(ls), ..
+    }) = value
+    else {
+        return Err(Error::new(
+            value.span(),
+            "preset value must be a string literal",
+        ));
+    };
+    ls.value()
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            // Keywords need no `r#` here, as with quoted names in `ignore("type")`.
+            Ident::parse_any
+                .parse_str(name)
+                .map(|mut id| {
+                    id.set_span(ls.span());
+                    Member::Named(canonical_ident(&id))
+                })
+                .map_err(|_| Error::new(ls.span(), format!("`{name}` is not a valid field name")))
+        })
+        .collect()
+}
+
+/// Resolves `meta.inner.field` to the top-level field `meta` it starts from.
+fn nested_root(path: &syn::ExprField) -> syn::Result<Member> {
+    match &*path.base {
+        Expr::Field(inner) => nested_root(inner),
+        base => parse_member(base, "ignore"),
+    }
+}
+
+/// Parses a single field reference.
+/// Identifiers name fields of regular structs, integer literals index tuple-struct fields.
+fn parse_member(arg: &Expr, name: &str) -> syn::Result<Member> {
+    match arg {
+        Expr::Path(p) => p
+            .path
+            .get_ident()
+            .map(|id| Member::Named(canonical_ident(id)))
+            .ok_or_else(|| Error::new(p.span(), format!("expected identifier in {name}(...)"))),
+        Expr::Lit(el) => match &el.lit {
+            Lit::Int(li) => Ok(Member::Unnamed(Index {
+                index: li.base10_parse()?,
+                span: li.span(),
+            })),
+            // `"updated_at"` for names pasted in programmatically; keywords need no `r#` here.
+            Lit::Str(ls) => ls
+                .parse_with(Ident::parse_any)
+                .map(|id| Member::Named(canonical_ident(&id)))
+                .map_err(|_| {
+                    Error::new(
+                        ls.span(),
+                        format!("`{}` is not a valid field name", ls.value()),
+                    )
+                }),
+            other => Err(Error::new(
+                other.span(),
+                format!("expected identifier or field index in {name}(...)"),
+            )),
         },
+        other => Err(Error::new(
+            other.span(),
+            format!("expected identifier in {name}(...)"),
+        )),
+    }
+}
+
+/// Parses `name(field = value, ...)` into `(field, value)` pairs for per-field options.
+fn parse_field_values<'a>(call: &'a ExprCall, name: &str) -> syn::Result<Vec<(Member, &'a Expr)>> {
+    call.args
+        .iter()
+        .map(|arg| {
+            let Expr::Assign(assign) = arg else {
+                return Err(Error::new(
+                    arg.span(),
+                    format!("expected `field = value` in {name}(...)"),
+                ));
+            };
+            Ok((parse_member(&assign.left, name)?, &*assign.right))
+        })
+        .collect()
+}
+
+/// Parses `with(field = "path::to::fn", ...)` and likewise `normalize(...)` into
+/// `(field, function)` pairs; `what` names the function in errors.
+fn parse_field_paths(
+    call: &ExprCall,
+    option: &str,
+    what: &str,
+) -> syn::Result<Vec<(Member, Path)>> {
+    parse_field_values(call, option)?
+        .into_iter()
+        .map(|(field, value)| match value {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(ls), ..
+            }) => Ok((field, ls.parse::<Path>()?)),
+            other => Err(Error::new(
+                other.span(),
+                format!("{what} must be a string literal path"),
+            )),
+        })
+        .collect()
+}
+
+/// Parses `recurse(meta = "eq_child", ...)` into `(field, child method)` pairs.
+fn parse_recurse(call: &ExprCall) -> syn::Result<Vec<(Member, Ident)>> {
+    parse_field_values(call, "recurse")?
+        .into_iter()
+        .map(|(field, value)| Ok((field, parse_name(value, "recurse")?)))
+        .collect()
+}
+
+/// Parses `type_with("f64" = "path::to::fn", ...)` into `(type, comparator)` pairs.
+fn parse_type_with(call: &ExprCall) -> syn::Result<Vec<(Type, Path)>> {
+    call.args
+        .iter()
+        .map(|arg| {
+            let Expr::Assign(assign) = arg else {
+                return Err(Error::new(
+                    arg.span(),
+                    "expected `\"Type\" = \"path::to::fn\"` in type_with(...)",
+                ));
+            };
+            let ty = parse_type(&assign.left, "type_with")?;
+            match &*assign.right {
+                Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(ls), ..
+                }) => Ok((ty, ls.parse::<Path>()?)),
+                other => Err(Error::new(
+                    other.span(),
+                    "comparator must be a string literal path",
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Parses `project(ts = "epoch_seconds", ...)` into `(field, projection method)` pairs.
+fn parse_project(call: &ExprCall) -> syn::Result<Vec<(Member, Ident)>> {
+    parse_field_values(call, "project")?
+        .into_iter()
+        .map(|(field, value)| Ok((field, parse_name(value, "project")?)))
+        .collect()
+}
+
+/// Parses `key(field = |v| v.id(), ...)` into `(field, closure)` pairs.
+fn parse_key(call: &ExprCall) -> syn::Result<Vec<(Member, syn::ExprClosure)>> {
+    parse_field_values(call, "key")?
+        .into_iter()
+        .map(|(field, value)| match value {
+            Expr::Closure(closure) => Ok((field, closure.clone())),
+            // The quoted form suits generated attributes, as for other string-valued options.
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(ls), ..
+            }) => Ok((field, ls.parse::<syn::ExprClosure>()?)),
+            other => Err(Error::new(
+                other.span(),
+                "key must be a closure such as `|c| c.id()` or `\"|c| c.id()\"`",
+            )),
+        })
+        .collect()
+}
+
+/// Parses `approx(field = 1e-9, ...)` into `(field, epsilon)` pairs.
+fn parse_approx(call: &ExprCall) -> syn::Result<Vec<(Member, LitFloat)>> {
+    parse_field_values(call, "approx")?
+        .into_iter()
+        .map(|(field, value)| match value {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Float(lf),
+                ..
+            }) => Ok((field, lf.clone())),
+            other => Err(Error::new(
+                other.span(),
+                "epsilon must be a float literal such as `1e-9` or `0.5`",
+            )),
+        })
+        .collect()
+}
+
+/// Parses `approx_const(field = EPS, ...)` into `(field, constant)` pairs.
+fn parse_approx_const(call: &ExprCall) -> syn::Result<Vec<(Member, Path)>> {
+    parse_field_values(call, "approx_const")?
+        .into_iter()
+        .map(|(field, value)| match value {
+            Expr::Path(p) if p.qself.is_none() => Ok((field, p.path.clone())),
+            other => Err(Error::new(
+                other.span(),
+                "epsilon must be the path of a constant such as `EPS_GEO`",
+            )),
+        })
+        .collect()
+}
+
+/// Parses `array_ignore(samples = [0, 7], ...)` into `(field, ignored indices)` pairs.
+fn parse_array_ignore(call: &ExprCall) -> syn::Result<Vec<(Member, Vec<LitInt>)>> {
+    parse_field_values(call, "array_ignore")?
+        .into_iter()
+        .map(|(field, value)| {
+            let Expr::Array(array) = value else {
+                return Err(Error::new(
+                    value.span(),
+                    "expected a list of indices such as `[0, 7]`",
+                ));
+            };
+            let mut indices: Vec<LitInt> = Vec::new();
+            for elem in &array.elems {
+                let index = match elem {
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Int(int), ..
+                    }) => int,
+                    other => {
+                        return Err(Error::new(
+                            other.span(),
+                            "array index must be an integer literal",
+                        ))
+                    }
+                };
+                let value = index.base10_parse::<usize>()?;
+                if indices
+                    .iter()
+                    .any(|i| i.base10_parse::<usize>().ok() == Some(value))
+                {
+                    return Err(Error::new(
+                        index.span(),
+                        format!("index {value} is listed twice"),
+                    ));
+                }
+                indices.push(index.clone());
+            }
+            Ok((field, indices))
+        })
+        .collect()
+}
+
+/// Parses `map_ignore_keys(settings = ["nonce", ...], ...)` into `(field, keys)` pairs.
+fn parse_map_ignore_keys(call: &ExprCall) -> syn::Result<Vec<(Member, Vec<Lit>)>> {
+    parse_field_values(call, "map_ignore_keys")?
+        .into_iter()
+        .map(|(field, value)| {
+            let Expr::Array(array) = value else {
+                return Err(Error::new(
+                    value.span(),
+                    "expected a list of keys such as `[\"nonce\"]`",
+                ));
+            };
+            let keys = array
+                .elems
+                .iter()
+                .map(|elem| match elem {
+                    Expr::Lit(lit) => Ok(lit.lit.clone()),
+                    other => Err(Error::new(other.span(), "map key must be a literal")),
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            Ok((field, keys))
+        })
+        .collect()
+}
+
+/// Parses the string literal naming a generated item, e.g. `method = "eq_meta"`.
+///
+/// The name must be a usable identifier (raw identifiers such as `"r#match"` included), so a typo
+/// is reported at the literal instead of surfacing as a broken expansion.
+fn parse_name(value: &Expr, key: &str) -> syn::Result<Ident> {
+    match value {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(ls), ..
+        }) => ls.parse::<Ident>().map_err(|_| {
+            let kind = match key {
+                "report" | "key_wrapper" | "diff_enum" => "type",
+                "fn" => "function",
+                "module" => "module",
+                _ => "method",
+            };
+            Error::new(
+                ls.span(),
+                format!("`{}` is not a valid {kind} name", ls.value()),
+            )
+        }),
+        other => Err(Error::new(
+            other.span(),
+            format!("{key} value must be a string literal"),
+        )),
+    }
+}
+
+/// Parses `where_bound = "T: MyTrait, U: Clone"` into where-clause predicates.
+fn parse_where_bound(value: &Expr) -> syn::Result<Vec<WherePredicate>> {
+    let Expr::Lit(syn::ExprLit {
+        lit: Lit::Str(ls), ..
+    }) = value
+    else {
+        return Err(Error::new(
+            value.span(),
+            "where_bound value must be a string literal",
+        ));
+    };
+    let predicates = ls
+        .parse_with(Punctuated::<WherePredicate, Comma>::parse_terminated)
+        .map_err(|err| {
+            Error::new(
+                ls.span(),
+                format!("malformed where_bound `{}`: {err}", ls.value()),
+            )
+        })?;
+    Ok(predicates.into_iter().collect())
+}
+
+/// Parses `method_suffix = "_v2"`, which must keep generated names valid identifiers.
+fn parse_suffix(value: &Expr) -> syn::Result<String> {
+    let Expr::Lit(syn::ExprLit {
+        lit: Lit::Str(ls), ..
+    }) = value
+    else {
+        return Err(Error::new(
+            value.span(),
+            "method_suffix value must be a string literal",
+        ));
+    };
+    let suffix = ls.value();
+    if syn::parse_str::<Ident>(&format!("eq{suffix}")).is_err() {
+        return Err(Error::new(
+            ls.span(),
+            format!("`{suffix}` cannot be appended to an identifier"),
+        ));
+    }
+    Ok(suffix)
+}
+
+/// Parses `vis = "pub(crate)"`; an empty string makes the generated methods private.
+fn parse_vis(value: &Expr) -> syn::Result<Visibility> {
+    match value {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(ls), ..
+        }) => ls.parse().map_err(|_| {
+            Error::new(
+                ls.span(),
+                format!(
+                    "`{}` is not a valid visibility; use \"\", \"pub\", \"pub(crate)\" or \"pub(super)\"",
+                    ls.value()
+                ),
+            )
+        }),
+        other => Err(Error::new(
+            other.span(),
+            "vis value must be a string literal",
+        )),
+    }
+}
+
+/// Parses a type given either as a string literal (`"ItemDto"`) or a bare path (`ItemDto`).
+fn parse_type(value: &Expr, key: &str) -> syn::Result<Type> {
+    match value {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(ls), ..
+        }) => ls.parse(),
+        Expr::Path(p) => Ok(Type::Path(syn::TypePath {
+            qself: p.qself.clone(),
+            path: p.path.clone(),
+        })),
+        other => Err(Error::new(
+            other.span(),
+            format!("{key} value must be a type name"),
+        )),
+    }
+}
+
+/// Parses a trait path given as a string literal or a bare path, e.g. `impl_trait = "MyCompare"`.
+fn parse_path(value: &Expr, key: &str) -> syn::Result<Path> {
+    match value {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(ls), ..
+        }) => ls.parse(),
+        Expr::Path(p) if p.qself.is_none() => Ok(p.path.clone()),
+        other => Err(Error::new(
+            other.span(),
+            format!("{key} value must be a trait path"),
+        )),
+    }
+}
+
+/// Parses `skip_if_both("str::is_empty", fields = (a, b))` into the predicate and its fields.
+fn parse_skip_if_both(call: &ExprCall) -> syn::Result<(Path, Vec<Member>)> {
+    let mut args = call.args.iter();
+    let predicate = match args.next() {
+        Some(Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(ls), ..
+        })) => ls.parse::<Path>()?,
+        _ => {
+            return Err(Error::new(
+                call.span(),
+                "expected `skip_if_both(\"path::to::predicate\", fields = (...))`",
+            ))
+        }
+    };
+    let fields = match args.next() {
+        Some(Expr::Assign(assign)) if matches!(&*assign.left, Expr::Path(p) if p.path.is_ident("fields")) => {
+            match &*assign.right {
+                Expr::Tuple(tuple) => tuple.elems.iter().collect::<Vec<_>>(),
+                Expr::Paren(paren) => vec![&*paren.expr],
+                other => vec![other],
+            }
+        }
         _ => {
-            return Error::new(input.span(), "subset_eq can only be applied to structs")
-                .to_compile_error()
-                .into();
+            return Err(Error::new(
+                call.span(),
+                "expected `fields = (...)` after the predicate in skip_if_both(...)",
+            ))
         }
     };
+    if let Some(extra) = args.next() {
+        return Err(Error::new(
+            extra.span(),
+            "unexpected argument in skip_if_both(...)",
+        ));
+    }
+    let fields = fields
+        .into_iter()
+        .map(|field| parse_member(field, "skip_if_both"))
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok((predicate, fields))
+}
 
-    if fields_to_compare.is_empty() {
-        return Error::new(
-            input.span(),
-            "no fields left to compare after ignoring specified ones",
-        )
-        .to_compile_error()
-        .into();
+/// Parses `group(header = (id, version), ...)` into `(group, fields)` pairs.
+fn parse_groups(call: &ExprCall) -> syn::Result<Vec<(Ident, Vec<Member>)>> {
+    call.args
+        .iter()
+        .map(|arg| {
+            let Expr::Assign(assign) = arg else {
+                return Err(Error::new(
+                    arg.span(),
+                    "expected `name = (field, ...)` in group(...)",
+                ));
+            };
+            let Some(name) = (match &*assign.left {
+                Expr::Path(p) => p.path.get_ident(),
+                _ => None,
+            }) else {
+                return Err(Error::new(
+                    assign.left.span(),
+                    "expected group name in group(...)",
+                ));
+            };
+            let fields = match &*assign.right {
+                Expr::Tuple(tuple) => tuple.elems.iter().collect::<Vec<_>>(),
+                Expr::Paren(paren) => vec![&*paren.expr],
+                other => vec![other],
+            };
+            let fields = fields
+                .into_iter()
+                .map(|field| parse_member(field, "group"))
+                .collect::<syn::Result<Vec<_>>>()?;
+            Ok((name.clone(), fields))
+        })
+        .collect()
+}
+
+/// Parses `variants(eq_a(x), eq_b(y, z))` into `(method, ignored fields)` pairs.
+fn parse_variants(call: &ExprCall) -> syn::Result<Vec<(Ident, Vec<Member>)>> {
+    call.args
+        .iter()
+        .map(|arg| {
+            let Expr::Call(variant) = arg else {
+                return Err(Error::new(
+                    arg.span(),
+                    "expected `method_name(field, ...)` in variants(...)",
+                ));
+            };
+            let Expr::Path(name) = &*variant.func else {
+                return Err(Error::new(
+                    variant.func.span(),
+                    "expected method name in variants(...)",
+                ));
+            };
+            let Some(name) = name.path.get_ident() else {
+                return Err(Error::new(
+                    name.span(),
+                    "expected method name in variants(...)",
+                ));
+            };
+            Ok((name.clone(), parse_member_list(variant, "variants")?))
+        })
+        .collect()
+}
+
+/// Named fields are addressed by identifier, tuple-struct fields by index.
+fn field_member(index: usize, field: &Field) -> Member {
+    match &field.ident {
+        Some(id) => Member::Named(canonical_ident(id)),
+        None => Member::Unnamed(Index::from(index)),
     }
+}
 
-    // Build tuple comparison to leverage existing `PartialEq` implementations.
-    let self_tuple = quote! { ( #( &self.#fields_to_compare, )* ) };
-    let other_tuple = quote! { ( #( &other.#fields_to_compare, )* ) };
+/// Spells an identifier the way it must be written, so `r#id` and `id` name the same field.
+///
+/// Identifiers compare by their spelling, so a raw prefix is kept only where it is required,
+/// i.e. for keywords such as `r#type`, which then round-trip into `self.r#type`.
+fn canonical_ident(id: &Ident) -> Ident {
+    let name = id.unraw().to_string();
+    match syn::parse_str::<Ident>(&name) {
+        Ok(_) => Ident::new(&name, id.span()),
+        // Path keywords cannot be raw, nor name a field; leave them to fail to match.
+        Err(_) if ["self", "Self", "super", "crate"].contains(&name.as_str()) => id.clone(),
+        Err(_) => Ident::new_raw(&name, id.span()),
+    }
+}
 
-    // Emit original struct plus the subset equality helper method.
-    let expanded = quote! {
-        #input
+/// Renders a field reference for diagnostics and generated names (`name`, `type` or `0`).
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(id) => id.unraw().to_string(),
+        Member::Unnamed(idx) => idx.index.to_string(),
+    }
+}
 
-        impl #struct_name {
-            /// Generated subset equality method ignoring the specified fields.
-            pub fn #method_name(&self, other: &Self) -> bool {
-                #self_tuple == #other_tuple
+/// Name of the main method when no `method = "..."` is given.
+///
+/// `SUBSET_EQ_DEFAULT_METHOD`, read while the macro runs, replaces the built-in `eq_subset_ignoring`.
+fn default_method() -> syn::Result<Ident> {
+    match std::env::var("SUBSET_EQ_DEFAULT_METHOD") {
+        Ok(name) => syn::parse_str::<Ident>(&name).map_err(|_| {
+            Error::new(
+                proc_macro2::Span::call_site(),
+                format!("`SUBSET_EQ_DEFAULT_METHOD` is set to `{name}`, which is not a valid method name"),
+            )
+        }),
+        Err(_) => Ok(format_ident!("eq_subset_ignoring")),
+    }
+}
+
+/// Reads `SUBSET_EQ_DEFAULT_METHOD` in the user's crate as well.
+///
+/// Cargo does not see variables a proc macro reads, and `proc_macro::tracked_env` is unstable, but
+/// it rebuilds a crate whose code reads a variable with `option_env!` when that variable changes.
+fn track_default_method() -> TokenStream2 {
+    quote! {
+        const _: ::core::option::Option<&str> = ::core::option_env!("SUBSET_EQ_DEFAULT_METHOD");
+    }
+}
+
+/// Names the `conditional` flag parameter skipping a field, e.g. `ignore_debug_info`.
+fn conditional_flag(field: &Member) -> Ident {
+    format_ident!("ignore_{}", member_name(field), span = field.span())
+}
+
+/// Names the `runtime_mask` constant holding a field's bit, e.g. `FIELD_UPDATED_AT`.
+fn mask_const(member: &Member, suffix: &str) -> Ident {
+    let name = format!("FIELD_{}{}", member_name(member), suffix).to_uppercase();
+    Ident::new(&name, member.span())
+}
+
+/// Converts a type name such as `UserProfile` or `HTTPRequest` to `user_profile` / `http_request`.
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev != '_' && (!prev.is_uppercase() || next_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+/// Converts a field name such as `updated_at` to `UpdatedAt`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// The procedural attribute macro entry point.  
+/// Usage example:
+/// `#[subset_eq(ignore(updated_at), method = "eq_no_meta")]`
+#[proc_macro_attribute]
+pub fn subset_eq(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Parse the item the attribute is applied to (should be a struct). Parsing any item first
+    // lets a type alias or function get a targeted error instead of a generic parse failure.
+    let input = match parse_macro_input!(item as syn::Item) {
+        syn::Item::Struct(item) => DeriveInput::from(item),
+        syn::Item::Enum(item) => DeriveInput::from(item),
+        syn::Item::Union(item) => DeriveInput::from(item),
+        other => {
+            return Error::new_spanned(
+                other,
+                "subset_eq must be applied directly to a struct definition, not a type alias or other item",
+            )
+            .into_compile_error()
+            .into();
+        }
+    };
+    // Parse our custom arguments.
+    let args = parse_macro_input!(attr as Args);
+    let debug_print = args.debug_print.then(|| input.ident.clone());
+
+    let tracking = track_default_method();
+    let output = expand(args, input)
+        .map(|(item, generated)| quote! { #item #generated #tracking })
+        .unwrap_or_else(Error::into_compile_error);
+    if let Some(name) = debug_print {
+        // Printed while compiling, so it shows up in the `cargo build` output.
+        eprintln!("subset_eq expansion for `{name}`:\n{output}");
+    }
+    output.into()
+}
+
+/// Derive form of [`macro@subset_eq`], for those who prefer per-field markers.
+///
+/// `#[derive(SubsetEq)]` takes the same options in a `#[subset(...)]` attribute on the type, e.g.
+/// `#[subset(method = "eq_meta", ne_method = "ne_meta")]`, and leaves out fields marked
+/// `#[subset(ignore)]`, which works like `#[subset_skip]`. Derive helper attributes are inert, so
+/// nothing needs stripping and the type is left untouched; the generated items are added next to it.
+#[proc_macro_derive(SubsetEq, attributes(subset))]
+pub fn derive_subset_eq(item: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(item as DeriveInput);
+    let args = match derive_args(&mut input) {
+        Ok(args) => args,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let debug_print = args.debug_print.then(|| input.ident.clone());
+
+    let tracking = track_default_method();
+    let output = expand(args, input)
+        .map(|(_, generated)| quote! { #generated #tracking })
+        .unwrap_or_else(Error::into_compile_error);
+    if let Some(name) = debug_print {
+        eprintln!("subset_eq expansion for `{name}`:\n{output}");
+    }
+    output.into()
+}
+
+/// Reads the options of `#[derive(SubsetEq)]` from the type's `#[subset(...)]` attribute and turns
+/// `#[subset(ignore)]` field markers into the `#[subset_skip]` markers [`expand`] understands.
+fn derive_args(input: &mut DeriveInput) -> syn::Result<Args> {
+    let mut options = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("subset"));
+    let args = match options.next() {
+        Some(attr) => attr.parse_args::<Args>()?,
+        None => syn::parse2::<Args>(TokenStream2::new())?,
+    };
+    if let Some(extra) = options.next() {
+        return Err(Error::new_spanned(
+            extra,
+            "put every option in a single `#[subset(...)]` on the type",
+        ));
+    }
+    let union = matches!(input.data, Data::Union(_));
+    let fields: Vec<&mut Field> = match &mut input.data {
+        Data::Struct(ds) => ds.fields.iter_mut().collect(),
+        Data::Enum(de) => de
+            .variants
+            .iter_mut()
+            .flat_map(|variant| variant.fields.iter_mut())
+            .collect(),
+        Data::Union(du) => du.fields.named.iter_mut().collect(),
+    };
+    for field in fields {
+        for attr in field.attrs.iter_mut() {
+            if !attr.path().is_ident("subset") {
+                continue;
+            }
+            let marker = attr.parse_args::<Ident>().ok().filter(|id| id == "ignore");
+            if marker.is_none() {
+                return Err(Error::new_spanned(
+                    &*attr,
+                    "expected `#[subset(ignore)]`; other options go on the type",
+                ));
             }
+            if union {
+                return Err(Error::new_spanned(
+                    &*attr,
+                    "`#[subset(ignore)]` is not supported on unions",
+                ));
+            }
+            *attr = syn::parse_quote_spanned!(attr.span()=> #[subset_skip]);
         }
+    }
+    Ok(args)
+}
+
+/// Builds the generated helpers, returned next to the item to re-emit, which has its
+/// `#[subset_skip]` markers removed.
+fn expand(args: Args, mut input: DeriveInput) -> syn::Result<(DeriveInput, TokenStream2)> {
+    match &input.data {
+        Data::Enum(_) => return expand_enum(args, input),
+        Data::Union(_) => return expand_union(args, input),
+        Data::Struct(_) => {}
+    }
+
+    check_struct_options(&args, &input)?;
+
+    let Args {
+        used,
+        mut ignored,
+        nested,
+        ignore_prefix,
+        ignore_type,
+        pub_only,
+        auto_skip_phantom,
+        only,
+        method,
+        as_trait,
+        impl_trait,
+        sealed_trait,
+        module,
+        variants,
+        groups,
+        allow_group_overlap,
+        no_std,
+        rules: rule_args,
+        compare_order,
+        diff,
+        try_method,
+        log_mismatch,
+        serde_names,
+        other,
+        ref_other,
+        ne_method,
+        full_method,
+        slice_method,
+        vis,
+        report,
+        first_diff,
+        diff_enum,
+        const_fn,
+        flexible_rhs,
+        runtime_mask,
+        conditional,
+        by_value,
+        bytewise,
+        check_bounds,
+        allow_empty,
+        strict,
+        exhaustive,
+        reflect,
+        score,
+        field_eq_iter,
+        invert,
+        free_fn,
+        gen_macro,
+        gen_proptest,
+        ord_method,
+        cmp_key_method,
+        hash_method,
+        profile_method,
+        key_wrapper,
+        fold_method,
+        changed_method,
+        method_suffix,
+        attrs,
+        derive_partial_eq,
+        derive_partial_ord,
+        derive_eq,
+        debug_print: _,
+        unsafe_union: _,
+        where_bound,
+    } = args;
+
+    // A sealed trait only differs in how it was declared, so it takes the `impl_trait` path.
+    let impl_trait = impl_trait.or(sealed_trait);
+
+    // Generated types follow the struct's own visibility unless one is requested, so a private
+    // struct's field types do not leak through a `pub` item.
+    let type_vis = vis.clone().unwrap_or_else(|| input.vis.clone());
+    // Keep the historical `pub` default when no visibility is requested.
+    let vis = vis.unwrap_or_else(|| syn::parse_quote!(pub));
+
+    // The inherent method is generated unless other outputs were requested instead;
+    // `ne_method`, `slice_method`, `gen_macro` and `gen_proptest` delegate to it, so they always
+    // need the positive method.
+    let other_outputs = as_trait
+        || !variants.is_empty()
+        || !groups.is_empty()
+        || diff.is_some()
+        || try_method.is_some()
+        || report.is_some()
+        || first_diff.is_some()
+        || score.is_some()
+        || field_eq_iter.is_some()
+        || free_fn.is_some()
+        || ord_method.is_some()
+        || cmp_key_method.is_some()
+        || hash_method.is_some()
+        || profile_method.is_some()
+        || key_wrapper.is_some()
+        || fold_method.is_some()
+        || changed_method.is_some()
+        || full_method.is_some()
+        || ref_other.is_some()
+        || derive_partial_eq;
+    let emit_method = module.is_none()
+        && (method.is_some()
+            || ne_method.is_some()
+            || slice_method.is_some()
+            || gen_macro
+            || gen_proptest
+            || impl_trait.is_some()
+            || runtime_mask
+            || !conditional.is_empty()
+            || !other_outputs);
+    // Determine generated method name, fallback if unspecified.
+    let method_name = match method {
+        Some(method) => method,
+        None => format_ident!("{}{}", default_method()?, method_suffix),
+    };
+    // Fields marked `#[subset_skip]` are ignored on top of `ignore(...)`.
+    let stacked = has_pending_invocation(&input);
+    let mut skipped = match &mut input.data {
+        Data::Struct(ds) => strip_skip_markers(&mut ds.fields, stacked)?,
+        _ => Vec::new(),
     };
 
-    expanded.into()
+    let struct_name = &input.ident;
+    let generics = with_predicates(&input.generics, where_bound);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let Data::Struct(ds) = &input.data else {
+        unreachable!("enums and unions are expanded separately");
+    };
+    let fields = &ds.fields;
+
+    // Attribute macros see the item before `#[cfg]` stripping, so this includes fields that are
+    // configured out; options naming them pass validation in every configuration.
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| field_member(i, f))
+        .collect::<Vec<_>>();
+
+    let cfgs = field_cfgs(fields)?;
+    // Names reported by `diff`, `try_method` and `field_eq_iter`.
+    let renames = if serde_names {
+        serde_renames(fields)
+    } else {
+        Vec::new()
+    };
+
+    // Prefix matches extend the explicit ignore list; a prefix matching nothing is a no-op.
+    ignored.extend(
+        members
+            .iter()
+            .filter(|m| has_prefix(m, &ignore_prefix))
+            .cloned(),
+    );
+    ignored.extend(
+        members
+            .iter()
+            .zip(fields.iter())
+            .filter(|(_, f)| has_type(&f.ty, &ignore_type))
+            .map(|(m, _)| m.clone()),
+    );
+    if pub_only {
+        ignored.extend(
+            members
+                .iter()
+                .zip(fields.iter())
+                .filter(|(_, f)| !matches!(f.vis, Visibility::Public(_)))
+                .map(|(m, _)| m.clone()),
+        );
+    }
+    if auto_skip_phantom {
+        skipped.extend(
+            members
+                .iter()
+                .zip(fields.iter())
+                .filter(|(_, f)| is_phantom(&f.ty))
+                .map(|(m, _)| m.clone()),
+        );
+    }
+
+    // Fields whose type mentions a type parameter need an explicit `PartialEq` bound.
+    let type_params = input
+        .generics
+        .type_params()
+        .map(|p| &p.ident)
+        .collect::<Vec<_>>();
+    let generic_fields = members
+        .iter()
+        .zip(fields.iter())
+        .filter(|(_, f)| mentions_any(f.ty.to_token_stream(), &type_params))
+        .map(|(m, f)| (m, &f.ty))
+        .collect::<Vec<_>>();
+
+    check_selectors(
+        struct_name,
+        fields,
+        &members,
+        ignored
+            .iter()
+            .chain(only.iter().flatten())
+            .chain(variants.iter().flat_map(|(_, ignored)| ignored))
+            .chain(groups.iter().flat_map(|(_, fields)| fields))
+            .chain(rule_args.with.iter().map(|(field, _)| field))
+            .chain(&compare_order),
+    )?;
+
+    if let Some(only) = &only {
+        // A typo in a whitelist would silently compare fewer fields, so reject it.
+        if let Some(unknown) = only.iter().find(|x| !members.contains(x)) {
+            return Err(Error::new(
+                unknown.span(),
+                format!("no field `{}` in `{struct_name}`", member_name(unknown)),
+            ));
+        }
+    }
+
+    // Collect all fields that are not ignored (or exactly the `only` ones, or with `invert`
+    // exactly the ignored ones). Filtering `members` keeps declaration order, whatever order the
+    // options list fields in; `diff`, `try_method` and the other per-field outputs rely on it.
+    let fields_to_compare = members
+        .iter()
+        .filter(|m| !skipped.contains(m))
+        .filter(|m| match &only {
+            Some(only) => only.contains(m),
+            None => ignored.contains(m) == invert,
+        })
+        .collect::<Vec<_>>();
+
+    // A subset covering every field is just full equality, usually from a typo in `ignore(...)`.
+    // Options leaving out only part of a field, or letting the caller skip it, still exclude.
+    let partially_excluded = !nested.is_empty()
+        || !rule_args.array_ignore.is_empty()
+        || !rule_args.map_ignore_keys.is_empty()
+        || !conditional.is_empty()
+        || runtime_mask;
+    if strict
+        && !members.is_empty()
+        && fields_to_compare.len() == members.len()
+        && !partially_excluded
+    {
+        return Err(Error::new(
+            option_span(&used, "strict"),
+            format!(
+                "`strict`: no field of `{struct_name}` is excluded, so the comparison is full equality; \
+                 check `ignore(...)` for misspelled field names"
+            ),
+        ));
+    }
+
+    // Omitting `ignore(...)` compares every field; only a struct that truly ends up with
+    // nothing to compare is an error, worded by whether any field existed to begin with.
+    // `allow_empty` opts a field-less struct into a comparison that always holds.
+    if members.is_empty() && allow_empty {
+        if score.is_some() {
+            return Err(Error::new(
+                option_span(&used, "score"),
+                format!("`score` is undefined for `{struct_name}`, which has no fields"),
+            ));
+        }
+    } else if fields_to_compare.is_empty() {
+        let message = if members.is_empty() {
+            format!(
+                "subset_eq requires at least one comparable field; `{struct_name}` has none \
+                 (add `allow_empty` to compare it as always equal)"
+            )
+        } else {
+            "no fields left to compare after ignoring specified ones".to_string()
+        };
+        return Err(Error::new(input.span(), message));
+    }
+
+    for (i, field) in conditional.iter().enumerate() {
+        let name = member_name(field);
+        if !members.contains(field) {
+            return Err(Error::new(
+                field.span(),
+                format!("no field `{name}` in `{struct_name}`"),
+            ));
+        }
+        if !fields_to_compare.contains(&field) {
+            return Err(Error::new(
+                field.span(),
+                format!("field `{name}` is ignored and cannot also use `conditional`"),
+            ));
+        }
+        if conditional[..i].contains(field) {
+            return Err(Error::new(
+                field.span(),
+                format!("field `{name}` is listed twice in `conditional`"),
+            ));
+        }
+        if runtime_mask && conditional_flag(field) == "ignore_mask" {
+            return Err(Error::new(
+                field.span(),
+                "the `conditional` flag for `mask` would clash with the `ignore_mask` parameter of `runtime_mask`",
+            ));
+        }
+    }
+    let rules = field_rules(
+        rule_args,
+        nested,
+        struct_name,
+        fields,
+        &members,
+        &fields_to_compare,
+    )?;
+
+    // Cheap, discriminating fields listed in `compare_order(...)` go first in the equality chain.
+    let mut listed = HashSet::new();
+    for field in &compare_order {
+        let name = member_name(field);
+        if !members.contains(field) {
+            return Err(Error::new(
+                field.span(),
+                format!("no field `{name}` in `{struct_name}`"),
+            ));
+        }
+        if !fields_to_compare.contains(&field) {
+            return Err(Error::new(
+                field.span(),
+                format!("field `{name}` is ignored and cannot also use `compare_order`"),
+            ));
+        }
+        if !listed.insert(name.clone()) {
+            return Err(Error::new(
+                field.span(),
+                format!("field `{name}` listed more than once in compare_order(...)"),
+            ));
+        }
+    }
+    if bytewise {
+        if !emit_method {
+            return Err(Error::new(
+                option_span(&used, "bytewise"),
+                "`bytewise` only changes the main method; add `method = \"...\"` to generate it",
+            ));
+        }
+        if fields_to_compare.len() != members.len() {
+            return Err(Error::new(
+                option_span(&used, "bytewise"),
+                "`bytewise` compares the whole value and cannot skip fields; remove the ignored fields or drop `bytewise`",
+            ));
+        }
+        if !rules.is_empty() {
+            return Err(Error::new(
+                option_span(&used, "bytewise"),
+                "`bytewise` compares raw bytes and cannot honor per-field rules such as `with(...)`",
+            ));
+        }
+    }
+    let eq_fields = prioritized(&fields_to_compare, &compare_order);
+
+    let subset = Subset {
+        used: &used,
+        struct_name,
+        generics: &generics,
+        fields,
+        members: &members,
+        compared: &fields_to_compare,
+        eq_fields: &eq_fields,
+        skipped: &skipped,
+        rules: &rules,
+        cfgs: &cfgs,
+        renames: &renames,
+        generic_fields: &generic_fields,
+        compare_order: &compare_order,
+        method_name: &method_name,
+        method_suffix: &method_suffix,
+        vis: &vis,
+        type_vis: &type_vis,
+        attrs: &attrs,
+        other: other.as_ref(),
+        const_fn,
+        by_value,
+        no_std,
+        log_mismatch,
+    };
+
+    let mut methods = Vec::new();
+    let mut user_trait_method = None;
+    if emit_method {
+        let (items, main_method) = build_main_method(
+            &subset,
+            flexible_rhs,
+            runtime_mask,
+            &conditional,
+            bytewise,
+            impl_trait.is_some(),
+        )?;
+        methods.extend(items);
+        match &impl_trait {
+            Some(_) => user_trait_method = Some(main_method),
+            None => methods.push(main_method),
+        }
+    }
+    if let Some(full_name) = full_method
+        .as_ref()
+        .filter(|name| emit_method && **name == method_name)
+    {
+        return Err(Error::new(
+            full_name.span(),
+            format!("`full_method` must differ from the subset method `{method_name}`"),
+        ));
+    }
+    let named_methods: [(&Option<Ident>, MethodBuilder); 13] = [
+        (&full_method, build_full_method),
+        (&ne_method, build_ne_method),
+        (&slice_method, build_slice_method),
+        (&diff, build_diff),
+        (&try_method, build_try_method),
+        (&field_eq_iter, build_field_eq_iter),
+        (&score, build_score),
+        (&ord_method, build_ord_method),
+        (&cmp_key_method, build_cmp_key_method),
+        (&hash_method, build_hash_method),
+        (&profile_method, build_profile_method),
+        (&fold_method, build_fold_method),
+        (&changed_method, build_changed_method),
+    ];
+    for (name, build) in named_methods {
+        if let Some(name) = name {
+            methods.push(build(&subset, name)?);
+        }
+    }
+    if gen_proptest {
+        methods.push(build_law_helpers(&subset));
+    }
+    methods.extend(build_name_consts(&subset, exhaustive, reflect));
+    methods.extend(
+        ref_other
+            .as_ref()
+            .map(|ref_ty| build_ref_method(&subset, ref_ty)),
+    );
+    methods.extend(build_variants(&subset, &variants)?);
+    methods.extend(build_groups(&subset, &groups, allow_group_overlap)?);
+    let diff_enum_item = match (&first_diff, &diff_enum) {
+        (Some(method), Some(enum_name)) => {
+            let (method, diff_enum) = build_first_diff(&subset, method, enum_name)?;
+            methods.push(method);
+            Some(diff_enum)
+        }
+        _ => None,
+    };
+    let report_struct = report.as_ref().map(|report_name| {
+        let (method, report) = build_report(&subset, report_name);
+        methods.push(method);
+        report
+    });
+
+    let inherent = (!methods.is_empty()).then(|| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                #( #methods )*
+            }
+        }
+    });
+    // Shared by the trait impls comparing `self` with `other` of the same type.
+    let body = subset.eq_body(&eq_fields, subset.ordered());
+    let trait_impl = as_trait.then(|| {
+        build_trait_impl(
+            &subset,
+            quote! { ::subset_eq_traits::SubsetEq },
+            quote! {
+                fn subset_eq(&self, other: &Self) -> bool {
+                    #body
+                }
+            },
+        )
+    });
+    let user_trait_impl = impl_trait.as_ref().map(|trait_path| {
+        build_trait_impl(
+            &subset,
+            trait_path.to_token_stream(),
+            quote! { #user_trait_method },
+        )
+    });
+    let partial_eq_impl = derive_partial_eq.then(|| {
+        build_trait_impl(
+            &subset,
+            quote! { ::core::cmp::PartialEq },
+            quote! {
+                fn eq(&self, other: &Self) -> bool {
+                    #body
+                }
+            },
+        )
+    });
+    let eq_impl = derive_eq.then(|| build_eq_impl(&subset));
+    let partial_ord_impl = derive_partial_ord
+        .then(|| build_partial_ord_impl(&subset))
+        .transpose()?;
+    let key_struct = key_wrapper
+        .as_ref()
+        .map(|key_name| build_key_wrapper(&subset, key_name))
+        .transpose()?;
+    let free_fn = free_fn
+        .as_ref()
+        .map(|fn_name| build_free_fn(&subset, fn_name, &vis));
+    let module_item = module
+        .as_ref()
+        .map(|module_name| build_module(&subset, module_name));
+    // Generic layouts are only known once instantiated; a concrete struct is checked right away so
+    // padding is reported even before the method gets called.
+    let padding_check = (bytewise && generics.params.is_empty()).then(|| {
+        let no_padding = no_padding_const(&method_suffix);
+        quote! {
+            const _: () = #struct_name::#no_padding;
+        }
+    });
+    let matches_macro = gen_macro.then(|| build_matches_macro(&subset));
+    let bound_checks = check_bounds.then(|| build_bound_checks(&subset));
+
+    Ok((
+        input,
+        quote! {
+            #inherent
+            #trait_impl
+            #user_trait_impl
+            #partial_eq_impl
+            #eq_impl
+            #partial_ord_impl
+            #free_fn
+            #module_item
+            #report_struct
+            #diff_enum_item
+            #key_struct
+            #matches_macro
+            #bound_checks
+            #padding_check
+        },
+    ))
+}
+
+/// Returns where an option was written, or the call site when it was not.
+fn option_span(used: &[Ident], name: &str) -> proc_macro2::Span {
+    used.iter()
+        .find(|o| *o == name)
+        .map_or_else(proc_macro2::Span::call_site, Ident::span)
+}
+
+/// Rejects struct options that contradict each other, before any field is looked at.
+fn check_struct_options(args: &Args, input: &DeriveInput) -> syn::Result<()> {
+    let option_span = |name: &str| option_span(&args.used, name);
+    if args.derive_eq && !args.derive_partial_eq {
+        return Err(Error::new(
+            option_span("derive_eq"),
+            "`derive_eq` requires `derive_partial_eq`",
+        ));
+    }
+    if args.derive_partial_ord && !args.derive_partial_eq {
+        return Err(Error::new(
+            option_span("derive_partial_ord"),
+            "`derive_partial_ord` requires `derive_partial_eq`, so that `==` agrees with `partial_cmp`",
+        ));
+    }
+    for (enabled, option, derived) in [
+        (args.derive_partial_eq, "derive_partial_eq", "PartialEq"),
+        (args.derive_eq, "derive_eq", "Eq"),
+        (args.derive_partial_ord, "derive_partial_ord", "PartialOrd"),
+    ] {
+        if let Some(span) = enabled.then(|| derive_span(input, derived)).flatten() {
+            let mut err = Error::new(
+                span,
+                format!("remove `{derived}` from `#[derive(...)]`; `{option}` implements it from the subset"),
+            );
+            err.combine(Error::new(
+                option_span(option),
+                format!("`{option}` requested here"),
+            ));
+            return Err(err);
+        }
+    }
+
+    if args.unsafe_union.is_some() {
+        return Err(Error::new(
+            option_span("unsafe_union"),
+            "`unsafe_union(...)` only applies to unions",
+        ));
+    }
+
+    if args.log_mismatch && args.const_fn {
+        return Err(Error::new(
+            option_span("log_mismatch"),
+            "`log_mismatch` cannot be combined with `const_fn`; logging is not const",
+        ));
+    }
+    if args.log_mismatch && args.bytewise {
+        return Err(Error::new(
+            option_span("log_mismatch"),
+            "`log_mismatch` cannot be combined with `bytewise`; the bytes are compared all at once",
+        ));
+    }
+
+    if args.flexible_rhs && args.const_fn {
+        return Err(Error::new(
+            option_span("flexible_rhs"),
+            "`flexible_rhs` cannot be combined with `const_fn`; trait methods such as `Borrow::borrow` are not const",
+        ));
+    }
+
+    for (reshaped, reshaping) in [
+        (args.runtime_mask, "runtime_mask"),
+        (!args.conditional.is_empty(), "conditional"),
+    ] {
+        if !reshaped {
+            continue;
+        }
+        // These call the main method with two arguments.
+        for (enabled, option) in [
+            (args.ne_method.is_some(), "ne_method"),
+            (args.slice_method.is_some(), "slice_method"),
+            (args.gen_macro, "gen_macro"),
+            (args.gen_proptest, "gen_proptest"),
+            (args.flexible_rhs, "flexible_rhs"),
+        ] {
+            if enabled {
+                return Err(Error::new(
+                    option_span(option),
+                    format!("`{option}` cannot be combined with `{reshaping}`"),
+                ));
+            }
+        }
+    }
+
+    if args.by_value {
+        // These pass the right-hand side by reference.
+        for (enabled, option) in [
+            (args.slice_method.is_some(), "slice_method"),
+            (args.flexible_rhs, "flexible_rhs"),
+        ] {
+            if enabled {
+                return Err(Error::new(
+                    option_span(option),
+                    format!("`{option}` cannot be combined with `by_value`"),
+                ));
+            }
+        }
+    }
+    if args.bytewise {
+        // These change what the main method receives, which the byte comparison relies on.
+        for (enabled, option) in [
+            (args.other.is_some(), "other"),
+            (args.by_value, "by_value"),
+            (args.runtime_mask, "runtime_mask"),
+            (!args.conditional.is_empty(), "conditional"),
+            (args.const_fn, "const_fn"),
+        ] {
+            if enabled {
+                return Err(Error::new(
+                    option_span(option),
+                    format!("`{option}` cannot be combined with `bytewise`"),
+                ));
+            }
+        }
+        if !has_repr_c(&input.attrs) {
+            return Err(Error::new(
+                option_span("bytewise"),
+                "`bytewise` requires `#[repr(C)]` on the struct, so its layout is predictable",
+            ));
+        }
+    }
+
+    if args.check_bounds && args.other.is_some() {
+        return Err(Error::new(
+            option_span("check_bounds"),
+            "`check_bounds` cannot be combined with `other = \"...\"`; fields are compared against another type",
+        ));
+    }
+
+    if args.impl_trait.is_some() && args.sealed_trait.is_some() {
+        return Err(Error::new(
+            option_span("sealed_trait"),
+            "`sealed_trait` cannot be combined with `impl_trait`; the main method goes into one trait impl",
+        ));
+    }
+    // A sealed trait only differs in how it was declared, so it takes the `impl_trait` path.
+    let trait_option = if args.sealed_trait.is_some() {
+        "sealed_trait"
+    } else {
+        "impl_trait"
+    };
+    let impl_trait = args.impl_trait.is_some() || args.sealed_trait.is_some();
+
+    if impl_trait && args.const_fn {
+        return Err(Error::new(
+            option_span(trait_option),
+            format!("`{trait_option}` cannot be combined with `const_fn`; trait methods cannot be const"),
+        ));
+    }
+
+    if args.module.is_some() {
+        // The free function replaces the method these build on or reshape.
+        for (enabled, option) in [
+            (args.ne_method.is_some(), "ne_method"),
+            (args.slice_method.is_some(), "slice_method"),
+            (args.gen_macro, "gen_macro"),
+            (args.gen_proptest, "gen_proptest"),
+            (impl_trait, trait_option),
+            (args.runtime_mask, "runtime_mask"),
+            (!args.conditional.is_empty(), "conditional"),
+            (args.bytewise, "bytewise"),
+            (args.by_value, "by_value"),
+            (args.flexible_rhs, "flexible_rhs"),
+        ] {
+            if enabled {
+                return Err(Error::new(
+                    option_span(option),
+                    format!("`{option}` cannot be combined with `module`; the main method becomes a free function"),
+                ));
+            }
+        }
+    }
+
+    if args.gen_proptest && args.other.is_some() {
+        return Err(Error::new(
+            option_span("gen_proptest"),
+            "`gen_proptest` cannot be combined with `other = \"...\"`; the laws compare a type with itself",
+        ));
+    }
+
+    if args.pub_only && args.only.is_some() {
+        return Err(Error::new(
+            option_span("pub_only"),
+            "`pub_only` cannot be combined with `only(...)`",
+        ));
+    }
+
+    if args.invert && args.only.is_some() {
+        return Err(Error::new(
+            option_span("invert"),
+            "`invert` cannot be combined with `only(...)`; list the fields in `only(...)` directly",
+        ));
+    }
+
+    if args.no_std {
+        for (fields, option) in [
+            (&args.rules.unordered, "unordered"),
+            (&args.rules.by_display, "by_display"),
+        ] {
+            if !fields.is_empty() {
+                return Err(Error::new(
+                    option_span(option),
+                    format!(
+                        "`{option}(...)` allocates with `std` and cannot be combined with `no_std`"
+                    ),
+                ));
+            }
+        }
+    }
+
+    match (&args.first_diff, &args.diff_enum) {
+        (Some(_), None) => Err(Error::new(
+            option_span("first_diff"),
+            "`first_diff` needs `diff_enum = \"...\"` to name the enum it returns",
+        )),
+        (None, Some(_)) => Err(Error::new(
+            option_span("diff_enum"),
+            "`diff_enum` only names the enum returned by `first_diff = \"...\"`",
+        )),
+        (Some(_), Some(_)) if !input.generics.params.is_empty() => Err(Error::new(
+            option_span("first_diff"),
+            "`first_diff` does not support generic structs; the enum cannot name their parameters",
+        )),
+        (Some(_), Some(_)) if args.other.is_some() => Err(Error::new(
+            option_span("first_diff"),
+            "`first_diff` cannot be combined with `other = \"...\"`; each variant holds two values of one type",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Turns the per-field comparison options into one rule per field, checking that each names a
+/// compared field and that no field gets two.
+fn field_rules(
+    options: RuleArgs,
+    nested: Vec<Member>,
+    struct_name: &Ident,
+    fields: &Fields,
+    members: &[Member],
+    fields_to_compare: &[&Member],
+) -> syn::Result<Vec<(Member, Compare)>> {
+    let RuleArgs {
+        with,
+        recurse,
+        approx,
+        approx_const,
+        array_ignore,
+        map_ignore_keys,
+        key,
+        project,
+        type_with,
+        wildcard_none,
+        default_wildcard,
+        skip_if_both,
+        deref,
+        by_ptr,
+        case_insensitive,
+        unordered,
+        by_display,
+        total,
+        normalize,
+    } = options;
+    let mut array_rules = Vec::new();
+    for (field, indices) in array_ignore {
+        // The kept indices are spelled out, so the length has to be known while expanding.
+        let len = members
+            .iter()
+            .zip(fields.iter())
+            .find(|(member, _)| **member == field)
+            .map(|(_, f)| match &f.ty {
+                Type::Array(array) => match &array.len {
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Int(len), ..
+                    }) => len
+                        .base10_parse::<usize>()
+                        .map_err(|e| Error::new(len.span(), e)),
+                    other => Err(Error::new(
+                        other.span(),
+                        "`array_ignore` needs the array length written as a literal",
+                    )),
+                },
+                other => Err(Error::new(
+                    other.span(),
+                    format!(
+                        "`array_ignore` needs an array field, but `{}` is not one",
+                        member_name(&field)
+                    ),
+                )),
+            })
+            .transpose()?;
+        let mut ignored = Vec::new();
+        for index in &indices {
+            let value = index.base10_parse::<usize>()?;
+            if let Some(len) = len.filter(|len| value >= *len) {
+                return Err(Error::new(
+                    index.span(),
+                    format!("index {value} is out of range for an array of length {len}"),
+                ));
+            }
+            ignored.push(value);
+        }
+        let kept = (0..len.unwrap_or(0))
+            .filter(|i| !ignored.contains(i))
+            .collect();
+        array_rules.push((field, Compare::ArrayExcept(kept), "array_ignore"));
+    }
+    let mut nested_roots: Vec<Member> = Vec::new();
+    for root in nested {
+        if !nested_roots.contains(&root) {
+            nested_roots.push(root);
+        }
+    }
+    let default_nested = default_method()?;
+    let listed = |fields: Vec<Member>, compare: Compare, option: &'static str| {
+        fields
+            .into_iter()
+            .map(move |field| (field, compare.clone(), option))
+    };
+    let requested =
+        with.into_iter()
+            .map(|(field, path)| (field, Compare::With(path), "with"))
+            .chain(
+                recurse
+                    .into_iter()
+                    .map(|(field, method)| (field, Compare::Nested(method), "recurse")),
+            )
+            .chain(listed(
+                nested_roots,
+                Compare::Nested(default_nested),
+                "ignore(field.nested)",
+            ))
+            .chain(approx.into_iter().map(|(field, epsilon)| {
+                (field, Compare::Approx(epsilon.to_token_stream()), "approx")
+            }))
+            .chain(approx_const.into_iter().map(|(field, epsilon)| {
+                (
+                    field,
+                    Compare::Approx(epsilon.to_token_stream()),
+                    "approx_const",
+                )
+            }))
+            .chain(array_rules)
+            .chain(
+                map_ignore_keys
+                    .into_iter()
+                    .map(|(field, keys)| (field, Compare::MapIgnoreKeys(keys), "map_ignore_keys")),
+            )
+            .chain(listed(
+                wildcard_none,
+                Compare::WildcardNone,
+                "wildcard_none",
+            ))
+            .chain(listed(
+                default_wildcard,
+                Compare::DefaultWildcard,
+                "default_wildcard",
+            ))
+            .chain(skip_if_both.into_iter().flat_map(|(predicate, fields)| {
+                listed(fields, Compare::SkipIfBoth(predicate), "skip_if_both")
+            }))
+            .chain(listed(deref, Compare::Deref, "deref"))
+            .chain(listed(by_ptr, Compare::ByPtr, "by_ptr"))
+            .chain(listed(
+                case_insensitive,
+                Compare::CaseInsensitive,
+                "case_insensitive",
+            ))
+            .chain(listed(unordered, Compare::Unordered, "unordered"))
+            .chain(listed(by_display, Compare::Display, "by_display"))
+            .chain(listed(total, Compare::Total, "total"))
+            .chain(
+                normalize
+                    .into_iter()
+                    .map(|(field, path)| (field, Compare::Normalize(path), "normalize")),
+            )
+            .chain(
+                key.into_iter()
+                    .map(|(field, closure)| (field, Compare::Key(closure), "key")),
+            )
+            .chain(
+                project
+                    .into_iter()
+                    .map(|(field, projection)| (field, Compare::Project(projection), "project")),
+            );
+
+    // Per-field comparison rules; fields without one use `==`.
+    let mut rules: Vec<(Member, Compare)> = Vec::new();
+    for (field, compare, option) in requested {
+        add_rule(
+            &mut rules,
+            struct_name,
+            members,
+            fields_to_compare,
+            field,
+            compare,
+            option,
+        )?;
+    }
+    // Type-level comparators fill in for fields without a rule of their own.
+    for (member, field) in members.iter().zip(fields.iter()) {
+        if !fields_to_compare.contains(&member) || rules.iter().any(|(r, _)| r == member) {
+            continue;
+        }
+        if let Some((_, path)) = type_with
+            .iter()
+            .find(|(ty, _)| has_type(&field.ty, std::slice::from_ref(ty)))
+        {
+            rules.push((member.clone(), Compare::With(path.clone())));
+        }
+    }
+    Ok(rules)
+}
+
+/// A struct analyzed for subset comparison, shared by the builders of every generated output.
+struct Subset<'a> {
+    /// Every option name as written, for pointing diagnostics at the option.
+    used: &'a [Ident],
+    struct_name: &'a Ident,
+    /// The struct's generics with the `where_bound` predicates appended.
+    generics: &'a syn::Generics,
+    fields: &'a Fields,
+    members: &'a [Member],
+    /// The compared fields, in declaration order.
+    compared: &'a [&'a Member],
+    /// The compared fields in the order the equality chain checks them.
+    eq_fields: &'a [&'a Member],
+    /// Fields out of every comparison, from `#[subset_skip]` or `auto_skip_phantom`.
+    skipped: &'a [Member],
+    rules: &'a [(Member, Compare)],
+    cfgs: &'a [(Member, Vec<Attribute>)],
+    /// Names reported by `diff`, `try_method` and `field_eq_iter` where they differ from the field.
+    renames: &'a [(Member, String)],
+    /// Fields whose type mentions a type parameter, which need an explicit bound.
+    generic_fields: &'a [(&'a Member, &'a Type)],
+    compare_order: &'a [Member],
+    method_name: &'a Ident,
+    method_suffix: &'a str,
+    /// Visibility of the generated methods and of the types they are part of.
+    vis: &'a Visibility,
+    /// Visibility of generated types holding field values, which follows the struct's own.
+    type_vis: &'a Visibility,
+    attrs: &'a [syn::Meta],
+    other: Option<&'a Type>,
+    const_fn: bool,
+    by_value: bool,
+    no_std: bool,
+    log_mismatch: bool,
+}
+
+impl Subset<'_> {
+    fn option_span(&self, name: &str) -> proc_macro2::Span {
+        option_span(self.used, name)
+    }
+
+    fn cfg(&self, field: &Member) -> &[Attribute] {
+        cfg_of(field, self.cfgs)
+    }
+
+    /// The check of one field between `self` and `other`.
+    fn field_eq(&self, field: &Member) -> TokenStream2 {
+        field_eq(field, self.rules, &Sides::methods())
+    }
+
+    /// The comparison of `fields` between `self` and `other`.
+    fn eq_body(&self, fields: &[&Member], chain: bool) -> TokenStream2 {
+        eq_body(fields, self.rules, self.cfgs, &Sides::methods(), chain)
+    }
+
+    fn ordered(&self) -> bool {
+        !self.compare_order.is_empty()
+    }
+
+    /// `#bound` for the generic field types among `compared`.
+    fn trait_bounds(&self, compared: &[&Member], bound: TokenStream2) -> Vec<WherePredicate> {
+        self.generic_fields
+            .iter()
+            .filter(|(m, _)| compared.contains(m))
+            .map(|(_, ty)| -> WherePredicate { parse_quote!(#ty: #bound) })
+            .collect()
+    }
+
+    /// `PartialEq` for the generic field types among `compared` that `rules` leaves to `==`.
+    fn eq_bounds(&self, compared: &[&Member], rules: &[(Member, Compare)]) -> Vec<WherePredicate> {
+        self.generic_fields
+            .iter()
+            .filter(|(m, _)| compared.contains(m) && !rules.iter().any(|(r, _)| r == *m))
+            .map(|(_, ty)| -> WherePredicate { parse_quote!(#ty: ::core::cmp::PartialEq) })
+            .collect()
+    }
+
+    /// The bounds of the subset comparison itself; `log_mismatch` also formats the compared fields.
+    fn bounds(&self) -> Vec<WherePredicate> {
+        let mut bounds = self.eq_bounds(self.compared, self.rules);
+        if self.log_mismatch {
+            bounds.extend(self.trait_bounds(self.compared, quote! { ::core::fmt::Debug }));
+        }
+        bounds
+    }
+
+    fn eq_where(&self) -> Option<TokenStream2> {
+        method_where(&self.bounds())
+    }
+
+    /// The compared fields with their types, in declaration order.
+    fn compared_fields(&self) -> impl Iterator<Item = (&Member, &Field)> {
+        self.members
+            .iter()
+            .zip(self.fields.iter())
+            .filter(|(member, _)| self.compared.contains(member))
+    }
+
+    fn reported_name(&self, field: &Member) -> String {
+        self.renames
+            .iter()
+            .find(|(m, _)| m == field)
+            .map_or_else(|| member_name(field), |(_, name)| name.clone())
+    }
+
+    /// A `&[&str]` of field names; gated fields keep their `#[cfg]` on the element.
+    fn name_list(&self, fields: &[&Member]) -> TokenStream2 {
+        let names = fields.iter().map(|field| member_name(field));
+        let field_cfgs = fields.iter().map(|field| self.cfg(field));
+        quote! { &[#( #( #field_cfgs )* #names, )*] }
+    }
+
+    fn constness(&self) -> Option<TokenStream2> {
+        self.const_fn.then(|| quote! { const })
+    }
+
+    /// `&` in front of the right-hand side type and argument, unless passed by value.
+    fn by_ref(&self) -> Option<TokenStream2> {
+        (!self.by_value).then(|| quote! { & })
+    }
+
+    fn receiver(&self) -> TokenStream2 {
+        if self.by_value {
+            quote! { self }
+        } else {
+            quote! { &self }
+        }
+    }
+}
+
+/// Name of the constant asserting that a `bytewise` struct has no padding.
+fn no_padding_const(suffix: &str) -> Ident {
+    format_ident!("__SUBSET_EQ_NO_PADDING{}", suffix.to_uppercase())
+}
+
+/// Builds the main method, after the constants it reads: the padding check of `bytewise` and
+/// the field bits of `runtime_mask`.
+fn build_main_method(
+    subset: &Subset,
+    flexible_rhs: bool,
+    runtime_mask: bool,
+    conditional: &[Member],
+    bytewise: bool,
+    in_trait: bool,
+) -> syn::Result<(Vec<TokenStream2>, TokenStream2)> {
+    let Subset {
+        struct_name,
+        members,
+        fields,
+        method_name,
+        method_suffix,
+        vis,
+        attrs,
+        ..
+    } = subset;
+    let by_ref = subset.by_ref();
+    // With `flexible_rhs` the right-hand side is any `Borrow` of the compared type,
+    // rebound to a reference before the body runs.
+    let rhs = |target: TokenStream2| {
+        if flexible_rhs {
+            (
+                quote! { <__SubsetRhs: ::core::borrow::Borrow<#target>> },
+                quote! { __SubsetRhs },
+                quote! { let other: &#target = ::core::borrow::Borrow::borrow(&other); },
+            )
+        } else {
+            (
+                TokenStream2::new(),
+                quote! { #by_ref #target },
+                TokenStream2::new(),
+            )
+        }
+    };
+    // With `runtime_mask` every check is skipped when its field's bit is set in the mask.
+    let mask_param = runtime_mask.then(|| quote! { , ignore_mask: u64 });
+    // With `conditional` each listed field's check is skipped while its flag is set.
+    let flag_params = conditional.iter().map(|field| {
+        let cfg = subset.cfg(field);
+        let flag = conditional_flag(field);
+        quote! { , #( #cfg )* #flag: bool }
+    });
+    let mask_param = quote! { #mask_param #( #flag_params )* };
+    let mut items = Vec::new();
+    let no_padding = no_padding_const(method_suffix);
+    if bytewise {
+        let sizes = members.iter().zip(fields.iter()).map(|(member, field)| {
+            let cfg = subset.cfg(member);
+            let ty = &field.ty;
+            quote! {
+                #( #cfg )*
+                let size = size + ::core::mem::size_of::<#ty>();
+                #( #cfg )*
+                assert_copy::<#ty>();
+            }
+        });
+        items.push(quote! {
+            #[doc(hidden)]
+            const #no_padding: () = {
+                const fn assert_copy<T: ::core::marker::Copy>() {}
+                let size = 0;
+                #( #sizes )*
+                assert!(
+                    size == ::core::mem::size_of::<Self>(),
+                    "`bytewise` requires a struct without padding",
+                );
+            };
+        });
+    }
+    let main_body = |chain: bool| {
+        if bytewise {
+            return quote! {
+                {
+                    let () = Self::#no_padding;
+                    let size = ::core::mem::size_of::<Self>();
+                    // SAFETY: both references point to live values of `Self`, which has no
+                    // padding (checked by the constant above), so all `size` bytes behind each
+                    // are initialized and readable for the duration of the borrow.
+                    unsafe {
+                        ::core::slice::from_raw_parts(self as *const Self as *const u8, size)
+                            == ::core::slice::from_raw_parts(other as *const Self as *const u8, size)
+                    }
+                }
+            };
+        }
+        if !runtime_mask && conditional.is_empty() && !subset.log_mismatch {
+            return subset.eq_body(subset.eq_fields, chain);
+        }
+        let checks = subset.eq_fields.iter().map(|field| {
+            let mut check = subset.field_eq(field);
+            if runtime_mask {
+                let bit = mask_const(field, method_suffix);
+                check = quote! { (ignore_mask & Self::#bit) != 0 || #check };
+            }
+            if conditional.contains(field) {
+                let flag = conditional_flag(field);
+                check = quote! { #flag || #check };
+            }
+            (field, subset.cfg(field), check)
+        });
+        if !subset.log_mismatch {
+            return all_checks(checks.map(|(_, cfg, check)| (cfg, check)).collect());
+        }
+        // Sequential checks, so the first mismatch is known when it is logged.
+        let checks = checks.map(|(field, cfg, check)| {
+            let message = format!(
+                "{struct_name}: `{}` differs: {{:?}} != {{:?}}",
+                subset.reported_name(field)
+            );
+            quote! {
+                #( #cfg )*
+                if !(#check) {
+                    if ::core::cfg!(debug_assertions) {
+                        ::log::debug!(#message, self.#field, other.#field);
+                    }
+                    return false;
+                }
+            }
+        });
+        quote! {
+            {
+                #( #checks )*
+                true
+            }
+        }
+    };
+    if runtime_mask {
+        if members.len() > 64 {
+            return Err(Error::new(
+                subset.option_span("runtime_mask"),
+                format!(
+                    "`runtime_mask` supports at most 64 fields; `{struct_name}` has {}",
+                    members.len()
+                ),
+            ));
+        }
+        for (i, member) in members.iter().enumerate() {
+            let bit = mask_const(member, method_suffix);
+            let shift = proc_macro2::Literal::usize_unsuffixed(i);
+            let cfg = subset.cfg(member);
+            let doc = format!(
+                "Mask bit skipping `{}` in `{method_name}`.",
+                member_name(member)
+            );
+            items.push(quote! {
+                #[doc = #doc]
+                #( #cfg )*
+                #vis const #bit: u64 = 1 << #shift;
+            });
+        }
+    }
+    // A trait method takes the trait's visibility and its bounds move to the impl.
+    let (method_vis, main_where) = if in_trait {
+        (None, None)
+    } else {
+        (Some(vis), subset.eq_where())
+    };
+    let constness = subset.constness();
+    let receiver = subset.receiver();
+    let main_method = match subset.other {
+        // Field types may differ between the structs, so compare field by field
+        // instead of through a single tuple `PartialEq`.
+        Some(other_ty) => {
+            let checks = main_body(true);
+            let (rhs_generic, rhs_ty, rebind) = rhs(quote! { #other_ty });
+            quote! {
+                /// Generated subset equality method comparing the shared fields of another type.
+                #( #[#attrs] )*
+                #method_vis #constness fn #method_name #rhs_generic(#receiver, other: #rhs_ty #mask_param) -> bool {
+                    #rebind
+                    #checks
+                }
+            }
+        }
+        None => {
+            let body = main_body(subset.const_fn || subset.ordered());
+            let (rhs_generic, rhs_ty, rebind) = rhs(quote! { Self });
+            quote! {
+                /// Generated subset equality method ignoring the specified fields.
+                #( #[#attrs] )*
+                #method_vis #constness fn #method_name #rhs_generic(#receiver, other: #rhs_ty #mask_param) -> bool #main_where {
+                    #rebind
+                    #body
+                }
+            }
+        }
+    };
+    Ok((items, main_method))
+}
+
+/// Builds a method generated from its name alone, such as the one named by `diff = "..."`.
+type MethodBuilder = fn(&Subset, &Ident) -> syn::Result<TokenStream2>;
+
+fn build_full_method(subset: &Subset, full_name: &Ident) -> syn::Result<TokenStream2> {
+    let Subset { vis, attrs, .. } = subset;
+    let all_fields = subset.members.iter().collect::<Vec<_>>();
+    let full_body = subset.eq_body(&all_fields, subset.const_fn);
+    let full_where = method_where(&subset.eq_bounds(&all_fields, subset.rules));
+    let constness = subset.constness();
+    Ok(quote! {
+        /// Generated equality method comparing every field, including the ignored ones.
+        #( #[#attrs] )*
+        #vis #constness fn #full_name(&self, other: &Self) -> bool #full_where {
+            #full_body
+        }
+    })
+}
+
+fn build_ne_method(subset: &Subset, ne_name: &Ident) -> syn::Result<TokenStream2> {
+    let Subset {
+        vis,
+        attrs,
+        method_name,
+        ..
+    } = subset;
+    let (other_ty, ne_where) = match subset.other {
+        Some(other_ty) => (quote! { #other_ty }, None),
+        None => (quote! { Self }, subset.eq_where()),
+    };
+    let constness = subset.constness();
+    let receiver = subset.receiver();
+    let by_ref = subset.by_ref();
+    Ok(quote! {
+        /// Generated negation of the subset equality method.
+        #( #[#attrs] )*
+        #vis #constness fn #ne_name(#receiver, other: #by_ref #other_ty) -> bool #ne_where {
+            !self.#method_name(other)
+        }
+    })
+}
+
+fn build_slice_method(subset: &Subset, slice_name: &Ident) -> syn::Result<TokenStream2> {
+    let Subset {
+        vis,
+        attrs,
+        method_name,
+        ..
+    } = subset;
+    let (other_ty, slice_where) = match subset.other {
+        Some(other_ty) => (quote! { #other_ty }, None),
+        None => (quote! { Self }, subset.eq_where()),
+    };
+    Ok(quote! {
+        /// Generated element-wise subset equality of two slices; differing lengths are unequal.
+        #( #[#attrs] )*
+        #vis fn #slice_name(a: &[Self], b: &[#other_ty]) -> bool #slice_where {
+            a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.#method_name(y))
+        }
+    })
+}
+
+fn build_diff(subset: &Subset, diff_name: &Ident) -> syn::Result<TokenStream2> {
+    let vis = subset.vis;
+    let eq_where = subset.eq_where();
+    let pushes = subset.compared.iter().map(|field| {
+        let name = subset.reported_name(field);
+        let eq = subset.field_eq(field);
+        let cfg = subset.cfg(field);
+        quote! {
+            #( #cfg )*
+            if !(#eq) {
+                differing.push(#name);
+            }
+        }
+    });
+    if subset.no_std {
+        // Sized for every compared field, so a full mismatch still fits.
+        let len = proc_macro2::Literal::usize_unsuffixed(subset.compared.len());
+        return Ok(quote! {
+            /// Generated helper listing the compared fields that differ, in declaration order.
+            ///
+            /// Only the first `count` names of the returned array are set.
+            #vis fn #diff_name(&self, other: &Self) -> ([&'static str; #len], usize) #eq_where {
+                struct Differing {
+                    names: [&'static str; #len],
+                    count: usize,
+                }
+                impl Differing {
+                    fn push(&mut self, name: &'static str) {
+                        self.names[self.count] = name;
+                        self.count += 1;
+                    }
+                }
+                let mut differing = Differing { names: [""; #len], count: 0 };
+                #( #pushes )*
+                (differing.names, differing.count)
+            }
+        });
+    }
+    Ok(quote! {
+        /// Generated helper listing the compared fields that differ, in declaration order.
+        #vis fn #diff_name(&self, other: &Self) -> ::std::vec::Vec<&'static str> #eq_where {
+            let mut differing = ::std::vec::Vec::new();
+            #( #pushes )*
+            differing
+        }
+    })
+}
+
+fn build_try_method(subset: &Subset, try_name: &Ident) -> syn::Result<TokenStream2> {
+    let vis = subset.vis;
+    let eq_where = subset.eq_where();
+    let checks = subset.compared.iter().map(|field| {
+        let name = subset.reported_name(field);
+        let eq = subset.field_eq(field);
+        let cfg = subset.cfg(field);
+        quote! {
+            #( #cfg )*
+            if !(#eq) {
+                return ::core::result::Result::Err(#name);
+            }
+        }
+    });
+    Ok(quote! {
+        /// Generated check returning the name of the first differing compared field, in declaration order.
+        #vis fn #try_name(&self, other: &Self) -> ::core::result::Result<(), &'static str> #eq_where {
+            #( #checks )*
+            ::core::result::Result::Ok(())
+        }
+    })
+}
+
+fn build_field_eq_iter(subset: &Subset, iter_name: &Ident) -> syn::Result<TokenStream2> {
+    let Subset { vis, compared, .. } = subset;
+    let eq_where = subset.eq_where();
+    let gated = compared.iter().any(|field| !subset.cfg(field).is_empty());
+    let pairs = compared.iter().map(|field| {
+        let name = subset.reported_name(field);
+        let eq = subset.field_eq(field);
+        quote! { (#name, #eq) }
+    });
+    // Array elements cannot carry `#[cfg]`, so gated fields are collected into a `Vec` instead,
+    // or written one by one into an array sized for all of them under `no_std`.
+    let entries = if gated && subset.no_std {
+        let len = proc_macro2::Literal::usize_unsuffixed(compared.len());
+        let sets = pairs.zip(compared.iter()).map(|(pair, field)| {
+            let cfg = subset.cfg(field);
+            quote! {
+                #( #cfg )*
+                {
+                    entries[count] = #pair;
+                    count += 1;
+                }
+            }
+        });
+        quote! {
+            let mut entries = [("", false); #len];
+            let mut count = 0;
+            #( #sets )*
+            ::core::iter::Iterator::take(::core::iter::IntoIterator::into_iter(entries), count)
+        }
+    } else if gated {
+        let pushes = pairs.zip(compared.iter()).map(|(pair, field)| {
+            let cfg = subset.cfg(field);
+            quote! {
+                #( #cfg )*
+                entries.push(#pair);
+            }
+        });
+        quote! {
+            let mut entries = ::std::vec::Vec::new();
+            #( #pushes )*
+            entries
+        }
+    } else {
+        quote! { [ #( #pairs ),* ] }
+    };
+    Ok(quote! {
+        /// Generated iterator over the compared fields and whether each one is equal, in declaration order.
+        #vis fn #iter_name(&self, other: &Self) -> impl ::core::iter::Iterator<Item = (&'static str, bool)> #eq_where {
+            let entries = { #entries };
+            ::core::iter::IntoIterator::into_iter(entries)
+        }
+    })
+}
+
+fn build_score(subset: &Subset, score_name: &Ident) -> syn::Result<TokenStream2> {
+    let vis = subset.vis;
+    let eq_where = subset.eq_where();
+    // The empty-field case is rejected before, so the denominator is only zero when every
+    // compared field is configured out; nothing then differs, as with `allow_empty`.
+    let tallies = subset.compared.iter().map(|field| {
+        let cfg = subset.cfg(field);
+        quote! {
+            #( #cfg )*
+            {
+                total += 1;
+            }
+        }
+    });
+    let counts = subset.compared.iter().map(|field| {
+        let eq = subset.field_eq(field);
+        let cfg = subset.cfg(field);
+        quote! {
+            #( #cfg )*
+            if #eq {
+                matching += 1;
+            }
+        }
+    });
+    Ok(quote! {
+        /// Generated similarity score: the fraction of compared fields that are equal, in `[0.0, 1.0]`.
+        ///
+        /// Returns `1.0` when every compared field is configured out.
+        #vis fn #score_name(&self, other: &Self) -> f64 #eq_where {
+            let mut total = 0usize;
+            #( #tallies )*
+            if total == 0 {
+                return 1.0;
+            }
+            let mut matching = 0usize;
+            #( #counts )*
+            matching as f64 / total as f64
+        }
+    })
+}
+
+fn build_ord_method(subset: &Subset, ord_name: &Ident) -> syn::Result<TokenStream2> {
+    // An ordering that disagreed with the custom equality would be a trap, so refuse instead.
+    if !subset.rules.is_empty() {
+        return Err(Error::new(
+            ord_name.span(),
+            "`ord_method` compares fields with `Ord` and cannot honor per-field rules such as `with(...)`",
+        ));
+    }
+    let vis = subset.vis;
+    let body = ord_body(subset.compared, subset.cfgs, false);
+    let ord_where =
+        method_where(&subset.trait_bounds(subset.compared, quote! { ::core::cmp::Ord }));
+    Ok(quote! {
+        /// Generated ordering over the compared fields, in declaration order.
+        #vis fn #ord_name(&self, other: &Self) -> ::core::cmp::Ordering #ord_where {
+            #body
+        }
+    })
+}
+
+fn build_cmp_key_method(subset: &Subset, key_name: &Ident) -> syn::Result<TokenStream2> {
+    if !subset.rules.is_empty() {
+        return Err(Error::new(
+            key_name.span(),
+            "`cmp_key_method` keys on the fields themselves and cannot honor per-field rules such as `with(...)`",
+        ));
+    }
+    if let Some(gated) = subset
+        .compared
+        .iter()
+        .find(|field| !subset.cfg(field).is_empty())
+    {
+        return Err(Error::new(
+            gated.span(),
+            format!(
+                "`cmp_key_method` cannot include the `#[cfg]`-gated field `{}`; ignore it to leave it out of the key",
+                member_name(gated)
+            ),
+        ));
+    }
+    let vis = subset.vis;
+    let key_types = subset.compared_fields().map(|(_, f)| &f.ty);
+    let clones = subset
+        .compared
+        .iter()
+        .map(|field| quote! { ::core::clone::Clone::clone(&self.#field) });
+    let key_where =
+        method_where(&subset.trait_bounds(subset.compared, quote! { ::core::clone::Clone }));
+    Ok(quote! {
+        /// Generated owned sort key holding clones of the compared fields, in declaration order.
+        #vis fn #key_name(&self) -> ( #( #key_types, )* ) #key_where {
+            ( #( #clones, )* )
+        }
+    })
+}
+
+fn build_hash_method(subset: &Subset, hash_name: &Ident) -> syn::Result<TokenStream2> {
+    // Equal values must hash equally, which a custom comparator gives no way to ensure.
+    if !subset.rules.is_empty() {
+        return Err(Error::new(
+            hash_name.span(),
+            "`hash_method` hashes fields with `Hash` and cannot honor per-field rules such as `with(...)`",
+        ));
+    }
+    let vis = subset.vis;
+    let hashes = subset.compared.iter().map(|field| {
+        let cfg = subset.cfg(field);
+        quote! {
+            #( #cfg )*
+            ::core::hash::Hash::hash(&self.#field, state);
+        }
+    });
+    let hash_where =
+        method_where(&subset.trait_bounds(subset.compared, quote! { ::core::hash::Hash }));
+    Ok(quote! {
+        /// Generated hash over the compared fields, consistent with the subset equality.
+        #vis fn #hash_name<__H: ::core::hash::Hasher>(&self, state: &mut __H) #hash_where {
+            #( #hashes )*
+        }
+    })
+}
+
+fn build_profile_method(subset: &Subset, profile_name: &Ident) -> syn::Result<TokenStream2> {
+    let Subset { vis, attrs, .. } = subset;
+    let float_types: [(Type, Ident); 2] = [
+        (parse_quote!(f64), format_ident!("f64_eq")),
+        (parse_quote!(f32), format_ident!("f32_eq")),
+    ];
+    // Floats without a rule of their own go through the profile, everything else as usual.
+    let mut profile_rules = subset.rules.to_vec();
+    for (member, field) in subset.compared_fields() {
+        if subset.rules.iter().any(|(r, _)| r == member) {
+            continue;
+        }
+        if let Some((_, method)) = float_types
+            .iter()
+            .find(|(ty, _)| has_type(&field.ty, std::slice::from_ref(ty)))
+        {
+            profile_rules.push((member.clone(), Compare::Profile(method.clone())));
+        }
+    }
+    let profile_body = eq_body(
+        subset.eq_fields,
+        &profile_rules,
+        subset.cfgs,
+        &Sides::methods(),
+        true,
+    );
+    let profile_where = method_where(&subset.eq_bounds(subset.compared, &profile_rules));
+    Ok(quote! {
+        /// Generated subset equality method comparing float fields through the profile `__P`.
+        #( #[#attrs] )*
+        #vis fn #profile_name<__P: ::subset_eq_traits::ComparisonProfile>(&self, other: &Self) -> bool #profile_where {
+            #profile_body
+        }
+    })
+}
+
+fn build_fold_method(subset: &Subset, fold_name: &Ident) -> syn::Result<TokenStream2> {
+    let vis = subset.vis;
+    let steps = subset.compared.iter().map(|field| {
+        let cfg = subset.cfg(field);
+        quote! {
+            #( #cfg )*
+            let acc = f(acc, &self.#field);
+        }
+    });
+    let fold_where =
+        method_where(&subset.trait_bounds(subset.compared, quote! { ::core::fmt::Debug }));
+    Ok(quote! {
+        /// Generated fold over the compared fields, in declaration order.
+        #vis fn #fold_name<__A, __F: ::core::ops::FnMut(__A, &dyn ::core::fmt::Debug) -> __A>(
+            &self,
+            init: __A,
+            mut f: __F,
+        ) -> __A #fold_where {
+            let acc = init;
+            #( #steps )*
+            acc
+        }
+    })
+}
+
+fn build_changed_method(subset: &Subset, changed_name: &Ident) -> syn::Result<TokenStream2> {
+    let vis = subset.vis;
+    // As with `invert`, `#[subset_skip]` and auto-skipped fields are out of both comparisons;
+    // they are often skipped for lacking `PartialEq` in the first place.
+    let left_out = subset
+        .members
+        .iter()
+        .filter(|m| !subset.compared.contains(m) && !subset.skipped.contains(m))
+        .collect::<Vec<_>>();
+    let checks = left_out.iter().map(|field| {
+        let cfg = subset.cfg(field);
+        quote! {
+            #( #cfg )*
+            if self.#field != other.#field {
+                return true;
+            }
+        }
+    });
+    // With nothing ignored, `other` is never read.
+    let unread = left_out.is_empty().then(|| quote! { let _ = other; });
+    let changed_where =
+        method_where(&subset.trait_bounds(&left_out, quote! { ::core::cmp::PartialEq }));
+    Ok(quote! {
+        /// Generated check telling whether any field left out of the comparison differs.
+        #vis fn #changed_name(&self, other: &Self) -> bool #changed_where {
+            #unread
+            #( #checks )*
+            false
+        }
+    })
+}
+
+/// Builds the associated functions `gen_proptest` asks for, one per equivalence law.
+fn build_law_helpers(subset: &Subset) -> TokenStream2 {
+    let Subset {
+        vis,
+        method_name,
+        method_suffix,
+        ..
+    } = subset;
+    let reflexive = format_ident!("__subset_reflexive{}", method_suffix);
+    let symmetric = format_ident!("__subset_symmetric{}", method_suffix);
+    let transitive = format_ident!("__subset_transitive{}", method_suffix);
+    let main_where = subset.eq_where();
+    let by_ref = subset.by_ref();
+    // `by_value` methods take a copy of each side.
+    let (a, b, c) = if subset.by_value {
+        (quote! { (*a) }, quote! { (*b) }, quote! { (*c) })
+    } else {
+        (quote! { a }, quote! { b }, quote! { c })
+    };
+    quote! {
+        /// Generated check that a value matches itself under the subset equality.
+        #[doc(hidden)]
+        #vis fn #reflexive(&self) -> bool #main_where {
+            let a = self;
+            #a.#method_name(#by_ref #a)
+        }
+
+        /// Generated check that the subset equality gives the same answer in both directions.
+        #[doc(hidden)]
+        #vis fn #symmetric(a: &Self, b: &Self) -> bool #main_where {
+            #a.#method_name(#by_ref #b) == #b.#method_name(#by_ref #a)
+        }
+
+        /// Generated check that matching `a` to `b` and `b` to `c` implies matching `a` to `c`.
+        #[doc(hidden)]
+        #vis fn #transitive(a: &Self, b: &Self, c: &Self) -> bool #main_where {
+            !(#a.#method_name(#by_ref #b) && #b.#method_name(#by_ref #c)) || #a.#method_name(#by_ref #c)
+        }
+    }
+}
+
+/// Builds the field-name constants of `exhaustive` and `reflect`.
+fn build_name_consts(subset: &Subset, exhaustive: bool, reflect: bool) -> Vec<TokenStream2> {
+    let vis = subset.vis;
+    let suffix = subset.method_suffix.to_uppercase();
+    let mut consts = Vec::new();
+    if exhaustive {
+        let fields_const = format_ident!("SUBSET_EQ_FIELDS{}", suffix);
+        let names = subset.name_list(subset.compared);
+        consts.push(quote! {
+            /// Names of the fields the generated comparison looks at, in declaration order.
+            #vis const #fields_const: &'static [&'static str] = #names;
+        });
+    }
+    if reflect {
+        let compared_const = format_ident!("SUBSET_EQ_COMPARED{}", suffix);
+        let ignored_const = format_ident!("SUBSET_EQ_IGNORED{}", suffix);
+        let compared = subset.name_list(subset.compared);
+        let left_out = subset.name_list(
+            &subset
+                .members
+                .iter()
+                .filter(|m| !subset.compared.contains(m))
+                .collect::<Vec<_>>(),
+        );
+        consts.push(quote! {
+            /// Names of the compared fields, in declaration order.
+            #vis const #compared_const: &'static [&'static str] = #compared;
+            /// Names of the fields left out of the comparison, in declaration order.
+            #vis const #ignored_const: &'static [&'static str] = #left_out;
+        });
+    }
+    consts
+}
+
+/// Builds `matches_ref`, comparing against the borrowed view `ref_other` names.
+fn build_ref_method(subset: &Subset, ref_ty: &Type) -> TokenStream2 {
+    let Subset { vis, attrs, .. } = subset;
+    let ref_name = format_ident!("matches_ref{}", subset.method_suffix);
+    let view = Sides {
+        deref_rhs: true,
+        ..Sides::methods()
+    };
+    let checks = eq_body(subset.eq_fields, subset.rules, subset.cfgs, &view, true);
+    let eq_where = subset.eq_where();
+    quote! {
+        /// Generated subset equality method comparing against a borrowed view, dereferencing its fields.
+        #( #[#attrs] )*
+        #vis fn #ref_name(&self, other: &#ref_ty) -> bool #eq_where {
+            #checks
+        }
+    }
+}
+
+/// Builds a subset equality method over `compared`, for `variants(...)` and `group(...)`.
+fn build_extra_method(
+    subset: &Subset,
+    name: &Ident,
+    compared: &[&Member],
+    doc: &str,
+) -> TokenStream2 {
+    let Subset { vis, attrs, .. } = subset;
+    let body = subset.eq_body(
+        &prioritized(compared, subset.compare_order),
+        subset.const_fn || subset.ordered(),
+    );
+    let extra_where = method_where(&subset.eq_bounds(compared, subset.rules));
+    let constness = subset.constness();
+    quote! {
+        #[doc = #doc]
+        #( #[#attrs] )*
+        #vis #constness fn #name(&self, other: &Self) -> bool #extra_where {
+            #body
+        }
+    }
+}
+
+fn build_variants(
+    subset: &Subset,
+    variants: &[(Ident, Vec<Member>)],
+) -> syn::Result<Vec<TokenStream2>> {
+    variants
+        .iter()
+        .map(|(variant_name, variant_ignored)| {
+            let compared = subset
+                .members
+                .iter()
+                .filter(|m| !variant_ignored.contains(m) && !subset.skipped.contains(m))
+                .collect::<Vec<_>>();
+            if compared.is_empty() {
+                return Err(Error::new(
+                    variant_name.span(),
+                    "no fields left to compare after ignoring specified ones",
+                ));
+            }
+            Ok(build_extra_method(
+                subset,
+                variant_name,
+                &compared,
+                "Generated subset equality method ignoring the specified fields.",
+            ))
+        })
+        .collect()
+}
+
+fn build_groups(
+    subset: &Subset,
+    groups: &[(Ident, Vec<Member>)],
+    allow_group_overlap: bool,
+) -> syn::Result<Vec<TokenStream2>> {
+    let Subset {
+        struct_name,
+        members,
+        ..
+    } = subset;
+    let mut grouped: Vec<&Member> = Vec::new();
+    let mut methods = Vec::new();
+    for (group, group_fields) in groups {
+        if group_fields.is_empty() {
+            return Err(Error::new(
+                group.span(),
+                format!("group `{group}` lists no fields"),
+            ));
+        }
+        for field in group_fields {
+            let name = member_name(field);
+            if !members.contains(field) {
+                return Err(Error::new(
+                    field.span(),
+                    format!("no field `{name}` in `{struct_name}`"),
+                ));
+            }
+            if grouped.contains(&field) && !allow_group_overlap {
+                return Err(Error::new(
+                    field.span(),
+                    format!("field `{name}` is already in another group; add `allow_group_overlap` to allow this"),
+                ));
+            }
+            grouped.push(field);
+        }
+        // Declaration order, as everywhere else, whatever order the group lists.
+        let compared = members
+            .iter()
+            .filter(|m| group_fields.contains(m))
+            .collect::<Vec<_>>();
+        let group_name = format_ident!(
+            "eq_{}{}",
+            group.unraw(),
+            subset.method_suffix,
+            span = group.span()
+        );
+        let doc = format!("Generated subset equality method comparing the `{group}` group.");
+        methods.push(build_extra_method(subset, &group_name, &compared, &doc));
+    }
+    Ok(methods)
+}
+
+/// Builds the `first_diff` method and the `diff_enum` it returns.
+fn build_first_diff(
+    subset: &Subset,
+    method: &Ident,
+    enum_name: &Ident,
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let Subset {
+        struct_name,
+        vis,
+        type_vis,
+        ..
+    } = subset;
+    let compared = subset.compared_fields().collect::<Vec<_>>();
+    let mut variants: Vec<Ident> = Vec::new();
+    for (member, _) in &compared {
+        let name = match member {
+            Member::Named(id) => pascal_case(&id.unraw().to_string()),
+            Member::Unnamed(idx) => format!("Field{}", idx.index),
+        };
+        let variant = Ident::new(&name, member.span());
+        if let Some(index) = variants.iter().position(|v| *v == variant) {
+            return Err(Error::new(
+                member.span(),
+                format!(
+                    "`{}` and `{}` both become the `{enum_name}::{variant}` variant; rename one of the fields",
+                    member_name(compared[index].0),
+                    member_name(member),
+                ),
+            ));
+        }
+        variants.push(variant);
+    }
+    // The enum is a separate item, where `Self` would mean the enum itself.
+    let tys = compared
+        .iter()
+        .map(|(_, field)| replace_self(field.ty.to_token_stream(), struct_name));
+    let variant_cfgs = compared
+        .iter()
+        .map(|(member, _)| subset.cfg(member))
+        .collect::<Vec<_>>();
+    let variant_docs = compared
+        .iter()
+        .map(|(member, _)| format!("`{}` differs; holds both values.", member_name(member)));
+    let checks = compared
+        .iter()
+        .zip(&variants)
+        .map(|((member, _), variant)| {
+            let eq = subset.field_eq(member);
+            let cfg = subset.cfg(member);
+            quote! {
+                #( #cfg )*
+                if !(#eq) {
+                    return ::core::option::Option::Some(#enum_name::#variant(
+                        ::core::clone::Clone::clone(&self.#member),
+                        ::core::clone::Clone::clone(&other.#member),
+                    ));
+                }
+            }
+        });
+    let eq_where = subset.eq_where();
+    let first_diff = quote! {
+        /// Generated check returning the first differing compared field with both values, in declaration order.
+        #vis fn #method(&self, other: &Self) -> ::core::option::Option<#enum_name> #eq_where {
+            #( #checks )*
+            ::core::option::Option::None
+        }
+    };
+    let doc = format!(
+        "The first compared field of `{struct_name}` that differs, generated by `subset_eq`."
+    );
+    let diff_enum = quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, PartialEq)]
+        #type_vis enum #enum_name {
+            #( #( #variant_cfgs )* #[doc = #variant_docs] #variants(#tys, #tys), )*
+        }
+    };
+    Ok((first_diff, diff_enum))
+}
+
+/// Builds `subset_diff` and the change report struct it returns.
+fn build_report(subset: &Subset, report_name: &Ident) -> (TokenStream2, TokenStream2) {
+    let Subset {
+        struct_name,
+        vis,
+        compared,
+        ..
+    } = subset;
+    let subset_diff = format_ident!("subset_diff{}", subset.method_suffix);
+    let flags = compared
+        .iter()
+        .map(|field| match field {
+            Member::Named(id) => format_ident!("{}_changed", id),
+            Member::Unnamed(idx) => format_ident!("field_{}_changed", idx.index),
+        })
+        .collect::<Vec<_>>();
+    let checks = compared.iter().map(|field| subset.field_eq(field));
+    let flag_cfgs = compared
+        .iter()
+        .map(|field| subset.cfg(field))
+        .collect::<Vec<_>>();
+    let eq_where = subset.eq_where();
+    let method = quote! {
+        /// Generated helper reporting which compared fields changed.
+        #vis fn #subset_diff(&self, other: &Self) -> #report_name #eq_where {
+            #report_name {
+                #( #( #flag_cfgs )* #flags: !(#checks), )*
+            }
+        }
+    };
+    let unchanged = all_checks(
+        flag_cfgs
+            .iter()
+            .zip(&flags)
+            .map(|(cfg, flag)| (*cfg, quote! { !self.#flag }))
+            .collect(),
+    );
+    let doc = format!("Per-field change report for `{struct_name}`, generated by `subset_eq`.");
+    let flag_docs = compared
+        .iter()
+        .map(|field| format!("Whether `{}` differs.", member_name(field)));
+    let report = quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #report_name {
+            #( #( #flag_cfgs )* #[doc = #flag_docs] #vis #flags: bool, )*
+        }
+
+        #[automatically_derived]
+        impl #report_name {
+            /// Returns `true` when no compared field changed.
+            #vis fn is_equal(&self) -> bool {
+                #unchanged
+            }
+        }
+    };
+    (method, report)
+}
+
+/// Builds an impl of `trait_path` for the struct around `items`, with the subset's bounds on it.
+fn build_trait_impl(
+    subset: &Subset,
+    trait_path: TokenStream2,
+    items: TokenStream2,
+) -> TokenStream2 {
+    // Trait impls cannot carry per-method bounds, so merge them into the impl's where-clause.
+    let generics = with_predicates(subset.generics, subset.bounds());
+    let (impl_generics, ty_generics, trait_where) = generics.split_for_impl();
+    let struct_name = subset.struct_name;
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #struct_name #ty_generics #trait_where {
+            #items
+        }
+    }
+}
+
+/// Builds the `Eq` impl of `derive_eq`.
+fn build_eq_impl(subset: &Subset) -> TokenStream2 {
+    // Unlike the `PartialEq` bounds these also cover concrete types, so a compared field that
+    // is not `Eq` (such as `f64`) is reported instead of silently accepted.
+    let generics = with_predicates(
+        subset.generics,
+        subset
+            .compared_fields()
+            .map(|(_, field)| -> WherePredicate {
+                let ty = &field.ty;
+                parse_quote!(#ty: ::core::cmp::Eq)
+            }),
+    );
+    let (impl_generics, ty_generics, eq_impl_where) = generics.split_for_impl();
+    let struct_name = subset.struct_name;
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::Eq for #struct_name #ty_generics #eq_impl_where {}
+    }
+}
+
+/// Builds the `PartialOrd` impl of `derive_partial_ord`.
+fn build_partial_ord_impl(subset: &Subset) -> syn::Result<TokenStream2> {
+    if !subset.rules.is_empty() {
+        return Err(Error::new(
+            subset.option_span("derive_partial_ord"),
+            "`derive_partial_ord` compares fields with `PartialOrd` and cannot honor per-field rules such as `with(...)`",
+        ));
+    }
+    let body = ord_body(subset.compared, subset.cfgs, true);
+    let generics = with_predicates(
+        subset.generics,
+        subset.trait_bounds(subset.compared, quote! { ::core::cmp::PartialOrd }),
+    );
+    let (impl_generics, ty_generics, ord_impl_where) = generics.split_for_impl();
+    let struct_name = subset.struct_name;
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialOrd for #struct_name #ty_generics #ord_impl_where {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                #body
+            }
+        }
+    })
+}
+
+/// Builds the newtype of `key_wrapper` with its `PartialEq`, `Eq` and `Hash` impls.
+fn build_key_wrapper(subset: &Subset, key_name: &Ident) -> syn::Result<TokenStream2> {
+    if !subset.rules.is_empty() {
+        return Err(Error::new(
+            key_name.span(),
+            "`key_wrapper` hashes fields with `Hash` and cannot honor per-field rules such as `with(...)`",
+        ));
+    }
+    let Subset {
+        struct_name,
+        type_vis,
+        compared,
+        ..
+    } = subset;
+    let (impl_generics, ty_generics, where_clause) = subset.generics.split_for_impl();
+    let sides = Sides {
+        lhs: quote! { self.0 },
+        rhs: quote! { other.0 },
+        deref_rhs: false,
+    };
+    let body = eq_body(compared, subset.rules, subset.cfgs, &sides, false);
+    let hashes = compared.iter().map(|field| {
+        let cfg = subset.cfg(field);
+        quote! {
+            #( #cfg )*
+            ::core::hash::Hash::hash(&self.0.#field, state);
+        }
+    });
+    // `Eq` is asserted of every compared field type, generic or not, as with `derive_eq`.
+    let generics = with_predicates(
+        subset.generics,
+        subset
+            .compared_fields()
+            .map(|(_, field)| -> WherePredicate {
+                let ty = &field.ty;
+                parse_quote!(#ty: ::core::cmp::Eq + ::core::hash::Hash)
+            }),
+    );
+    let (_, _, key_where) = generics.split_for_impl();
+    let doc = format!(
+        "Owning wrapper around `{struct_name}` whose equality and hash cover only the compared fields, generated by `subset_eq`."
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #type_vis struct #key_name #impl_generics (#type_vis #struct_name #ty_generics) #where_clause;
+
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialEq for #key_name #ty_generics #key_where {
+            fn eq(&self, other: &Self) -> bool {
+                #body
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::Eq for #key_name #ty_generics #key_where {}
+
+        #[automatically_derived]
+        impl #impl_generics ::core::hash::Hash for #key_name #ty_generics #key_where {
+            fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                #( #hashes )*
+            }
+        }
+    })
+}
+
+/// Builds the free function of `fn = "..."`, also used inside the module of `module = "..."`.
+fn build_free_fn(subset: &Subset, fn_name: &Ident, fn_vis: &Visibility) -> TokenStream2 {
+    let Subset {
+        struct_name, attrs, ..
+    } = subset;
+    let (_, ty_generics, _) = subset.generics.split_for_impl();
+    let rhs_ty = match subset.other {
+        Some(other_ty) => quote! { #other_ty },
+        None => quote! { #struct_name #ty_generics },
+    };
+    let sides = Sides {
+        lhs: quote! { a },
+        rhs: quote! { b },
+        deref_rhs: false,
+    };
+    let body = eq_body(
+        subset.eq_fields,
+        subset.rules,
+        subset.cfgs,
+        &sides,
+        subset.const_fn || subset.other.is_some() || subset.ordered(),
+    );
+    // A free function has no impl to inherit from, so it carries the struct's generics itself.
+    let generics = with_predicates(subset.generics, subset.bounds());
+    let (fn_generics, _, fn_where) = generics.split_for_impl();
+    let constness = subset.constness();
+    quote! {
+        /// Generated subset equality function ignoring the specified fields.
+        #( #[#attrs] )*
+        #fn_vis #constness fn #fn_name #fn_generics(a: &#struct_name #ty_generics, b: &#rhs_ty) -> bool #fn_where {
+            #body
+        }
+    }
+}
+
+/// Builds the module of `module = "..."`, holding the main method as a free function.
+fn build_module(subset: &Subset, module_name: &Ident) -> TokenStream2 {
+    // The module's own visibility bounds the function, which is `pub` to be reachable from outside.
+    let function = build_free_fn(subset, subset.method_name, &parse_quote!(pub));
+    let doc = format!(
+        "Generated subset equality helpers for `{}`.",
+        subset.struct_name
+    );
+    let vis = subset.vis;
+    quote! {
+        #[doc = #doc]
+        #vis mod #module_name {
+            #[allow(unused_imports)]
+            use super::*;
+            #function
+        }
+    }
+}
+
+/// Builds the `<struct>_matches!` shorthand of `gen_macro`.
+fn build_matches_macro(subset: &Subset) -> TokenStream2 {
+    let Subset {
+        struct_name,
+        method_name,
+        ..
+    } = subset;
+    let macro_name = format_ident!(
+        "{}_matches{}",
+        snake_case(&struct_name.unraw().to_string()),
+        subset.method_suffix,
+        span = struct_name.span()
+    );
+    let by_ref = subset.by_ref();
+    quote! {
+        /// Generated shorthand for the subset equality method, `a.method(&b)`.
+        #[allow(unused_macros)]
+        macro_rules! #macro_name {
+            ($a:expr, $b:expr $(,)?) => {
+                $a.#method_name(#by_ref $b)
+            };
+        }
+    }
+}
+
+/// Builds the `PartialEq` assertions of `check_bounds`: one per field compared with plain `==`,
+/// spanned at its type so a missing `PartialEq` is reported there. Generic field types cannot
+/// be named from a `const`.
+fn build_bound_checks(subset: &Subset) -> TokenStream2 {
+    let generic_params = subset
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(t) => &t.ident,
+            GenericParam::Lifetime(l) => &l.lifetime.ident,
+            GenericParam::Const(c) => &c.ident,
+        })
+        .collect::<Vec<_>>();
+    // The checks sit outside any impl, so `Self` is spelled out; in a generic struct it
+    // stands for the generic type itself and is skipped like the parameters.
+    let self_ty = format_ident!("Self");
+    let asserts = subset
+        .compared_fields()
+        .filter(|(m, f)| {
+            !subset.rules.iter().any(|(r, _)| r == *m)
+                && !mentions_any(f.ty.to_token_stream(), &generic_params)
+                && (generic_params.is_empty() || !mentions_any(f.ty.to_token_stream(), &[&self_ty]))
+        })
+        .map(|(m, f)| {
+            let ty = replace_self(f.ty.to_token_stream(), subset.struct_name);
+            let cfg = subset.cfg(m);
+            quote_spanned! {ty.span()=>
+                #( #cfg )*
+                assert_partial_eq::<#ty>();
+            }
+        });
+    quote! {
+        const _: fn() = || {
+            fn assert_partial_eq<T: ?::core::marker::Sized + ::core::cmp::PartialEq>() {}
+            #( #asserts )*
+        };
+    }
+}
+
+/// Options that keep their meaning on enums; everything else relies on a single field list.
+const ENUM_OPTIONS: &[&str] = &[
+    "ignore",
+    "ignore_prefix",
+    "ignore_type",
+    "only",
+    "method",
+    "ne_method",
+    "as_trait",
+    "vis",
+    "const_fn",
+    "method_suffix",
+    "attrs",
+    "debug_print",
+    "where_bound",
+];
+
+/// Builds the subset comparison for enums: same-variant pairs compare their non-ignored fields,
+/// pairs of different variants never compare equal.
+fn expand_enum(args: Args, mut input: DeriveInput) -> syn::Result<(DeriveInput, TokenStream2)> {
+    if let Some(option) = args
+        .used
+        .iter()
+        .find(|o| !ENUM_OPTIONS.iter().any(|supported| *o == supported))
+    {
+        return Err(Error::new(
+            option.span(),
+            format!("`{option}` is not supported on enums"),
+        ));
+    }
+    let Args {
+        ignored,
+        nested,
+        ignore_prefix,
+        ignore_type,
+        only,
+        method,
+        as_trait,
+        ne_method,
+        vis,
+        const_fn,
+        method_suffix,
+        attrs,
+        where_bound,
+        ..
+    } = args;
+    if let Some(root) = nested.first() {
+        return Err(Error::new(
+            root.span(),
+            "dotted paths in `ignore(...)` are not supported on enums",
+        ));
+    }
+    if let Some(only) = &only {
+        // As on structs, a typo in a whitelist would silently compare fewer fields; a name is
+        // valid when any variant declares it.
+        let Data::Enum(data) = &input.data else {
+            unreachable!("expand_enum is only called for enums");
+        };
+        let members = data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter().enumerate())
+            .map(|(i, f)| field_member(i, f))
+            .collect::<Vec<_>>();
+        if let Some(unknown) = only.iter().find(|x| !members.contains(x)) {
+            return Err(Error::new(
+                unknown.span(),
+                format!(
+                    "no field `{}` in any variant of `{}`",
+                    member_name(unknown),
+                    input.ident
+                ),
+            ));
+        }
+    }
+
+    let vis = vis.unwrap_or_else(|| parse_quote!(pub));
+    let method_name = match &method {
+        Some(method) => method.clone(),
+        None => format_ident!("{}{}", default_method()?, method_suffix),
+    };
+    let emit_method = method.is_some() || ne_method.is_some() || !as_trait;
+    let constness = const_fn.then(|| quote! { const });
+
+    let type_params = input
+        .generics
+        .type_params()
+        .map(|p| p.ident.clone())
+        .collect::<Vec<_>>();
+    let type_params = type_params.iter().collect::<Vec<_>>();
+
+    let stacked = has_pending_invocation(&input);
+    let Data::Enum(data) = &mut input.data else {
+        unreachable!("expand_enum is only called for enums");
+    };
+    let mut arms = Vec::new();
+    let mut bounds: Vec<WherePredicate> = Vec::new();
+    for variant in &mut data.variants {
+        let skipped = strip_skip_markers(&mut variant.fields, stacked)?;
+        let cfgs = field_cfgs(&variant.fields)?;
+        let variant_name = &variant.ident;
+        let mut self_bindings = Vec::new();
+        let mut other_bindings = Vec::new();
+        let mut checks = Vec::new();
+        for (i, f) in variant.fields.iter().enumerate() {
+            let member = field_member(i, f);
+            // Ignored names apply to every variant that declares such a field.
+            let compared = !skipped.contains(&member)
+                && match &only {
+                    Some(only) => only.contains(&member),
+                    None => {
+                        !ignored.contains(&member)
+                            && !has_prefix(&member, &ignore_prefix)
+                            && !has_type(&f.ty, &ignore_type)
+                    }
+                };
+            if !compared {
+                self_bindings.push((member, None));
+                other_bindings.push(None);
+                continue;
+            }
+            let name = member_name(&member);
+            let lhs = format_ident!("__self_{}", name);
+            let rhs = format_ident!("__other_{}", name);
+            checks.push((cfg_of(&member, &cfgs), quote! { *#lhs == *#rhs }));
+            if mentions_any(f.ty.to_token_stream(), &type_params) {
+                let ty = &f.ty;
+                bounds.push(parse_quote!(#ty: ::core::cmp::PartialEq));
+            }
+            self_bindings.push((member, Some(lhs)));
+            other_bindings.push(Some(rhs));
+        }
+        let (self_pat, other_pat) = match &variant.fields {
+            Fields::Named(_) => {
+                let bound = |bindings: Vec<(&Member, &Ident)>| {
+                    let (names, idents): (Vec<_>, Vec<_>) = bindings.into_iter().unzip();
+                    let gates = names.iter().map(|name| cfg_of(name, &cfgs));
+                    quote! { Self::#variant_name { #( #( #gates )* #names: #idents, )* .. } }
+                };
+                let self_pairs = self_bindings
+                    .iter()
+                    .filter_map(|(m, b)| b.as_ref().map(|b| (m, b)))
+                    .collect();
+                let other_pairs = self_bindings
+                    .iter()
+                    .zip(&other_bindings)
+                    .filter_map(|((m, _), b)| b.as_ref().map(|b| (m, b)))
+                    .collect();
+                (bound(self_pairs), bound(other_pairs))
+            }
+            Fields::Unnamed(_) => {
+                let positional = |bindings: Vec<Option<&Ident>>| {
+                    let slots = bindings.into_iter().map(|b| match b {
+                        Some(b) => quote! { #b },
+                        None => quote! { _ },
+                    });
+                    quote! { Self::#variant_name( #( #slots ),* ) }
+                };
+                (
+                    positional(self_bindings.iter().map(|(_, b)| b.as_ref()).collect()),
+                    positional(other_bindings.iter().map(Option::as_ref).collect()),
+                )
+            }
+            Fields::Unit => (
+                quote! { Self::#variant_name },
+                quote! { Self::#variant_name },
+            ),
+        };
+        let body = if checks.is_empty() {
+            quote! { true }
+        } else {
+            all_checks(checks)
+        };
+        arms.push(quote! { (#self_pat, #other_pat) => #body, });
+    }
+
+    // A single variant already covers every pair, so the fallback arm would be unreachable.
+    let body = match data.variants.len() {
+        0 => quote! { match *self {} },
+        1 => quote! { match (self, other) { #( #arms )* } },
+        _ => quote! { match (self, other) { #( #arms )* _ => false, } },
+    };
+
+    let enum_name = &input.ident;
+    let generics = with_predicates(&input.generics, where_bound);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let eq_where = method_where(&bounds);
+
+    let mut methods = Vec::new();
+    if emit_method {
+        methods.push(quote! {
+            /// Generated subset equality method ignoring the specified fields.
+            #( #[#attrs] )*
+            #vis #constness fn #method_name(&self, other: &Self) -> bool #eq_where {
+                #body
+            }
+        });
+    }
+    if let Some(ne_name) = &ne_method {
+        methods.push(quote! {
+            /// Generated negation of the subset equality method.
+            #( #[#attrs] )*
+            #vis #constness fn #ne_name(&self, other: &Self) -> bool #eq_where {
+                !self.#method_name(other)
+            }
+        });
+    }
+    let trait_impl = as_trait.then(|| {
+        let mut generics = generics.clone();
+        generics.make_where_clause().predicates.extend(bounds.clone());
+        let (_, _, trait_where) = generics.split_for_impl();
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::subset_eq_traits::SubsetEq for #enum_name #ty_generics #trait_where {
+                fn subset_eq(&self, other: &Self) -> bool {
+                    #body
+                }
+            }
+        }
+    });
+
+    let inherent = (!methods.is_empty()).then(|| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                #( #methods )*
+            }
+        }
+    });
+
+    Ok((
+        input,
+        quote! {
+            #inherent
+            #trait_impl
+        },
+    ))
+}
+
+/// Options that make sense on unions, whose fields can only be compared when named explicitly.
+const UNION_OPTIONS: &[&str] = &[
+    "unsafe_union",
+    "method",
+    "ne_method",
+    "vis",
+    "method_suffix",
+    "attrs",
+    "debug_print",
+    "where_bound",
+];
+
+/// Builds the subset comparison for unions: the fields named in `unsafe_union(...)` are read
+/// on both sides and compared with `==`.
+fn expand_union(args: Args, input: DeriveInput) -> syn::Result<(DeriveInput, TokenStream2)> {
+    if let Some(option) = args
+        .used
+        .iter()
+        .find(|o| !UNION_OPTIONS.iter().any(|supported| *o == supported))
+    {
+        return Err(Error::new(
+            option.span(),
+            format!("`{option}` is not supported on unions"),
+        ));
+    }
+    let Args {
+        unsafe_union,
+        method,
+        ne_method,
+        vis,
+        method_suffix,
+        attrs,
+        where_bound,
+        ..
+    } = args;
+    let union_name = &input.ident;
+    let Data::Union(data) = &input.data else {
+        unreachable!("only called for unions");
+    };
+    // Any field may be the active one, so comparing "all" of them is never meaningful.
+    let Some(active) = unsafe_union.filter(|active| !active.is_empty()) else {
+        return Err(Error::new(
+            input.span(),
+            format!(
+                "`{union_name}` is a union; name the fields to compare with `unsafe_union(field, ...)`"
+            ),
+        ));
+    };
+    let members = data
+        .fields
+        .named
+        .iter()
+        .filter_map(|f| f.ident.as_ref())
+        .map(canonical_ident)
+        .map(Member::Named)
+        .collect::<Vec<_>>();
+    let mut listed = HashSet::new();
+    for field in &active {
+        let name = member_name(field);
+        if !members.contains(field) {
+            return Err(Error::new(
+                field.span(),
+                format!("no field `{name}` in `{union_name}`"),
+            ));
+        }
+        if !listed.insert(name.clone()) {
+            return Err(Error::new(
+                field.span(),
+                format!("field `{name}` is listed more than once in `unsafe_union(...)`"),
+            ));
+        }
+    }
+
+    let vis = vis.unwrap_or_else(|| parse_quote!(pub));
+    let method_name = match &method {
+        Some(method) => method.clone(),
+        None => format_ident!("{}{}", default_method()?, method_suffix),
+    };
+    let generics = with_predicates(&input.generics, where_bound);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let checks = active
+        .iter()
+        .map(|field| quote! { self.#field == other.#field });
+    // SAFETY (for the generated block): the fields are plain reads, and `unsafe_union(...)` is the
+    // user's promise that every listed field is initialized and valid whichever one was written,
+    // as with `Copy` primitives sharing the same bytes.
+    let mut methods = vec![quote! {
+        /// Generated subset equality method comparing the listed union fields.
+        ///
+        /// Every listed field is read regardless of which one was written last; this is only
+        /// sound when each of them is valid for any bit pattern the union can hold.
+        #( #[#attrs] )*
+        #vis fn #method_name(&self, other: &Self) -> bool {
+            unsafe { #( (#checks) )&&* }
+        }
+    }];
+    if let Some(ne_name) = &ne_method {
+        methods.push(quote! {
+            /// Generated negation of the subset equality method.
+            #( #[#attrs] )*
+            #vis fn #ne_name(&self, other: &Self) -> bool {
+                !self.#method_name(other)
+            }
+        });
+    }
+
+    let generated = quote! {
+        #[automatically_derived]
+        impl #impl_generics #union_name #ty_generics #where_clause {
+            #( #methods )*
+        }
+    };
+    Ok((input, generated))
+}
+
+/// Finds `name` in the item's `#[derive(...)]` lists, returning the span of the derive entry.
+fn derive_span(input: &DeriveInput, name: &str) -> Option<proc_macro2::Span> {
+    input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Path, Comma>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find(|path| path.segments.last().is_some_and(|s| s.ident == name))
+        .map(|path| path.span())
+}
+
+/// Reports whether another `#[subset_eq(...)]` below this one will expand on the same item.
+fn has_pending_invocation(input: &DeriveInput) -> bool {
+    input.attrs.iter().any(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "subset_eq")
+    })
+}
+
+/// Removes `#[subset_skip]` markers from the fields, returning the marked fields.
+///
+/// The marker only means something to this macro; stripping it keeps the re-emitted item from
+/// tripping over an unknown attribute. When another `#[subset_eq]` is still pending on the item
+/// (`keep`), the markers stay for it and the last invocation removes them.
+fn strip_skip_markers(fields: &mut Fields, keep: bool) -> syn::Result<Vec<Member>> {
+    let mut skipped = Vec::new();
+    for (i, f) in fields.iter_mut().enumerate() {
+        let mut skip = None;
+        f.attrs.retain(|attr| {
+            let is_skip = attr.path().is_ident("subset_skip");
+            if is_skip {
+                skip = Some(attr.meta.require_path_only().map(|_| ()));
+            }
+            keep || !is_skip
+        });
+        if let Some(marker) = skip {
+            marker?;
+            skipped.push(field_member(i, f));
+        }
+    }
+    Ok(skipped)
+}
+
+/// Reports whether the item carries `#[repr(C)]`, possibly next to other hints such as `packed`.
+fn has_repr_c(attrs: &[Attribute]) -> bool {
+    attrs.iter().filter(|a| a.path().is_ident("repr")).any(|a| {
+        a.parse_args_with(Punctuated::<syn::Meta, Comma>::parse_terminated)
+            .is_ok_and(|hints| hints.iter().any(|hint| hint.path().is_ident("C")))
+    })
+}
+
+/// Reports whether a field type is written as `PhantomData`, under any path and with any arguments.
+fn is_phantom(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) if p.qself.is_none() => p
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+/// Reports whether a field type matches one of the `ignore_type(...)` types.
+///
+/// Macros only see types as written, so matching is textual. For plain paths one side may be a
+/// shorter suffix of the other, letting `Instant` and `std::time::Instant` match either way; there
+/// is no way to tell whether they resolve to the same type.
+fn has_type(ty: &Type, types: &[Type]) -> bool {
+    let segments = |ty: &Type| match ty {
+        Type::Path(p) if p.qself.is_none() => Some(
+            p.path
+                .segments
+                .iter()
+                .map(|s| s.to_token_stream().to_string())
+                .collect::<Vec<_>>(),
+        ),
+        _ => None,
+    };
+    let field = segments(ty);
+    let text = ty.to_token_stream().to_string();
+    types
+        .iter()
+        .any(|candidate| match (&field, segments(candidate)) {
+            (Some(field), Some(candidate)) => {
+                field.ends_with(&candidate) || candidate.ends_with(field)
+            }
+            _ => candidate.to_token_stream().to_string() == text,
+        })
+}
+
+/// Reports whether a named field starts with one of the `ignore_prefix(...)` prefixes.
+fn has_prefix(member: &Member, prefixes: &[String]) -> bool {
+    match member {
+        Member::Named(id) => {
+            let name = id.unraw().to_string();
+            prefixes.iter().any(|prefix| name.starts_with(prefix))
+        }
+        Member::Unnamed(_) => false,
+    }
+}
+
+/// Returns the item's generics with extra where-clause predicates appended.
+fn with_predicates(
+    generics: &syn::Generics,
+    predicates: impl IntoIterator<Item = WherePredicate>,
+) -> syn::Generics {
+    let mut generics = generics.clone();
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
+
+/// Builds a method-level where-clause from the collected bounds, if any.
+fn method_where(bounds: &[WherePredicate]) -> Option<TokenStream2> {
+    (!bounds.is_empty()).then(|| quote! { where #( #bounds, )* })
+}
+
+/// Reports whether a token stream mentions any of the given identifiers.
+fn mentions_any(tokens: TokenStream2, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(id) => idents.iter().any(|p| **p == id),
+        TokenTree::Group(g) => mentions_any(g.stream(), idents),
+        _ => false,
+    })
+}
+
+/// Replaces every `Self` in `tokens` with `ty`, for field types copied out of the type's own impls.
+fn replace_self(tokens: TokenStream2, ty: &Ident) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(id) if id == "Self" => {
+                TokenTree::Ident(Ident::new(&ty.to_string(), id.span()))
+            }
+            TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), replace_self(g.stream(), ty));
+                group.set_span(g.span());
+                TokenTree::Group(group)
+            }
+            tt => tt,
+        })
+        .collect()
+}
+
+/// Rejects field selectors that cannot refer to a field of the struct.
+fn check_selectors<'a>(
+    struct_name: &Ident,
+    fields: &Fields,
+    members: &[Member],
+    selectors: impl IntoIterator<Item = &'a Member>,
+) -> syn::Result<()> {
+    for selected in selectors {
+        match (selected, fields) {
+            (Member::Unnamed(idx), Fields::Unnamed(_)) if idx.index as usize >= members.len() => {
+                return Err(Error::new(
+                    idx.span,
+                    format!(
+                        "field index {} is out of range; `{struct_name}` has {} fields",
+                        idx.index,
+                        members.len()
+                    ),
+                ));
+            }
+            (Member::Unnamed(idx), Fields::Named(_)) => {
+                return Err(Error::new(
+                    idx.span,
+                    format!("`{struct_name}` has named fields; refer to them by name"),
+                ));
+            }
+            (Member::Named(id), Fields::Unnamed(_)) => {
+                return Err(Error::new(
+                    id.span(),
+                    format!("`{struct_name}` is a tuple struct; refer to its fields by index"),
+                ));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// How a single compared field is checked for equality.
+#[derive(Clone)]
+enum Compare {
+    /// Calls a user comparator as `path(&self.f, &other.f)`.
+    With(Path),
+    /// Accepts an absolute difference up to the epsilon, a literal or a constant; NaN never
+    /// compares equal.
+    Approx(TokenStream2),
+    /// Delegates to the nested struct's own subset method, `self.f.method(&other.f)`.
+    Nested(Ident),
+    /// Treats `None` on either side as "don't care", otherwise compares with `==`.
+    WildcardNone,
+    /// Treats the `Default` value on either side as "don't care", otherwise compares with `==`.
+    DefaultWildcard,
+    /// Skips the field when the predicate holds for both sides, otherwise compares with `==`.
+    SkipIfBoth(Path),
+    /// Compares the pointed-to values, `*self.f == *other.f`.
+    Deref,
+    /// Compares the addresses of the pointed-to values, `core::ptr::eq(&*self.f, &*other.f)`.
+    ByPtr,
+    /// Compares text ignoring ASCII case, `self.f.eq_ignore_ascii_case(&other.f)`.
+    CaseInsensitive,
+    /// Compares collections as multisets by sorting references to their elements.
+    Unordered,
+    /// Compares the `Display` output of both sides.
+    Display,
+    /// Compares floats by their total order, `self.f.total_cmp(&other.f) == Ordering::Equal`.
+    Total,
+    /// Compares both sides after normalization, `path(&self.f) == path(&other.f)`.
+    Normalize(Path),
+    /// Compares the keys a closure extracts from both sides with `==`.
+    Key(syn::ExprClosure),
+    /// Compares clones of two maps with the listed keys removed from both.
+    MapIgnoreKeys(Vec<Lit>),
+    /// Compares floats through the profile parameter of `profile_method`, `__P::f64_eq(self.f, other.f)`.
+    Profile(Ident),
+    /// Compares an array at the kept indices only, `self.f[1] == other.f[1] && ...`.
+    ArrayExcept(Vec<usize>),
+    /// Compares the results of a method called on both sides, `self.f.m() == other.f.m()`.
+    Project(Ident),
+}
+
+impl Compare {
+    /// Builds the equality check for one field given both sides as place expressions.
+    fn expr(&self, lhs: &TokenStream2, rhs: &TokenStream2) -> TokenStream2 {
+        match self {
+            Compare::With(path) => quote! { #path(&#lhs, &#rhs) },
+            Compare::Approx(epsilon) => quote! { (#lhs - #rhs).abs() <= #epsilon },
+            Compare::Nested(method) => quote! { #lhs.#method(&#rhs) },
+            // Only type-checks for `Option` fields, which is the point.
+            Compare::WildcardNone => {
+                quote! { #lhs.is_none() || #rhs.is_none() || #lhs == #rhs }
+            }
+            // A helper pins the `Default` type to the field's, which `== Default::default()`
+            // leaves ambiguous for types such as `String` with several `PartialEq` impls.
+            Compare::DefaultWildcard => quote! {
+                {
+                    fn is_default<T: ::core::default::Default + ::core::cmp::PartialEq>(value: &T) -> bool {
+                        *value == T::default()
+                    }
+                    is_default(&#lhs) || is_default(&#rhs) || #lhs == #rhs
+                }
+            },
+            Compare::SkipIfBoth(predicate) => quote! {
+                (#predicate(&#lhs) && #predicate(&#rhs)) || #lhs == #rhs
+            },
+            Compare::Deref => quote! { *#lhs == *#rhs },
+            Compare::ByPtr => quote! { ::core::ptr::eq(&*#lhs, &*#rhs) },
+            Compare::CaseInsensitive => quote! { #lhs.eq_ignore_ascii_case(&#rhs) },
+            Compare::Display => quote! {
+                ::std::string::ToString::to_string(&#lhs) == ::std::string::ToString::to_string(&#rhs)
+            },
+            Compare::Total => quote! {
+                #lhs.total_cmp(&#rhs) == ::core::cmp::Ordering::Equal
+            },
+            Compare::Normalize(path) => quote! { #path(&#lhs) == #path(&#rhs) },
+            Compare::Unordered => quote! {
+                {
+                    let mut __lhs = ::core::iter::IntoIterator::into_iter(&#lhs)
+                        .collect::<::std::vec::Vec<_>>();
+                    let mut __rhs = ::core::iter::IntoIterator::into_iter(&#rhs)
+                        .collect::<::std::vec::Vec<_>>();
+                    __lhs.len() == __rhs.len() && {
+                        __lhs.sort();
+                        __rhs.sort();
+                        __lhs == __rhs
+                    }
+                }
+            },
+            Compare::Profile(method) => quote! { __P::#method(#lhs, #rhs) },
+            Compare::MapIgnoreKeys(keys) => {
+                // `remove` borrows the key: string literals already are `&str`, for `Borrow<str>`.
+                let keys = keys.iter().map(|key| match key {
+                    Lit::Str(_) => quote! { #key },
+                    _ => quote! { &#key },
+                });
+                quote! {
+                    {
+                        let mut __lhs = ::core::clone::Clone::clone(&#lhs);
+                        let mut __rhs = ::core::clone::Clone::clone(&#rhs);
+                        #(
+                            __lhs.remove(#keys);
+                            __rhs.remove(#keys);
+                        )*
+                        __lhs == __rhs
+                    }
+                }
+            }
+            Compare::ArrayExcept(kept) if kept.is_empty() => quote! { true },
+            Compare::ArrayExcept(kept) => {
+                let kept = kept
+                    .iter()
+                    .map(|i| proc_macro2::Literal::usize_unsuffixed(*i));
+                quote! { #( #lhs[#kept] == #rhs[#kept] )&&* }
+            }
+            Compare::Project(projection) => quote! { #lhs.#projection() == #rhs.#projection() },
+            // Passing the closure through a function pins its argument type, which a directly
+            // called closure would leave too open for method calls such as `c.id()`.
+            Compare::Key(closure) => quote! {
+                {
+                    fn key_of<T: ?::core::marker::Sized, K>(value: &T, key: impl ::core::ops::FnOnce(&T) -> K) -> K {
+                        key(value)
+                    }
+                    key_of(&#lhs, #closure) == key_of(&#rhs, #closure)
+                }
+            },
+        }
+    }
+}
+
+/// Registers a comparison rule for a field that must exist and be compared.
+fn add_rule(
+    rules: &mut Vec<(Member, Compare)>,
+    struct_name: &Ident,
+    members: &[Member],
+    compared: &[&Member],
+    field: Member,
+    compare: Compare,
+    option: &str,
+) -> syn::Result<()> {
+    let name = member_name(&field);
+    if !members.contains(&field) {
+        return Err(Error::new(
+            field.span(),
+            format!("no field `{name}` in `{struct_name}`"),
+        ));
+    }
+    if !compared.contains(&&field) {
+        return Err(Error::new(
+            field.span(),
+            format!("field `{name}` is ignored and cannot also use `{option}`"),
+        ));
+    }
+    if rules.iter().any(|(existing, _)| *existing == field) {
+        return Err(Error::new(
+            field.span(),
+            format!("field `{name}` already has a comparison rule"),
+        ));
+    }
+    rules.push((field, compare));
+    Ok(())
+}
+
+/// Builds the equality expression over the compared fields.
+///
+/// Plain fields reuse tuple comparison to leverage existing `PartialEq` implementations; as
+/// soon as a field carries a custom rule the comparison becomes a short-circuiting `&&` chain.
+/// `chain` forces the `&&` form, e.g. for `const fn` where tuple `==` is unavailable. A tuple
+/// cannot drop a `#[cfg]`-gated element, so conditionally-compiled fields also leave the tuple form.
+fn eq_body(
+    fields: &[&Member],
+    rules: &[(Member, Compare)],
+    cfgs: &[(Member, Vec<Attribute>)],
+    sides: &Sides,
+    chain: bool,
+) -> TokenStream2 {
+    let gated = fields.iter().any(|field| !cfg_of(field, cfgs).is_empty());
+    if rules.is_empty() && !chain && !gated {
+        let Sides { lhs, rhs, .. } = sides;
+        let self_tuple = quote! { ( #( &#lhs.#fields, )* ) };
+        let other_tuple = quote! { ( #( &#rhs.#fields, )* ) };
+        return quote! { #self_tuple == #other_tuple };
+    }
+    all_checks(
+        fields
+            .iter()
+            .map(|field| (cfg_of(field, cfgs), field_eq(field, rules, sides)))
+            .collect(),
+    )
+}
+
+/// Builds the lexicographic ordering over the compared fields, through `PartialOrd` when
+/// `partial` is set and `Ord` otherwise.
+///
+/// Like [`eq_body`] this reuses tuple ordering, falling back to early returns when a field is
+/// `#[cfg]`-gated.
+fn ord_body(fields: &[&Member], cfgs: &[(Member, Vec<Attribute>)], partial: bool) -> TokenStream2 {
+    let (cmp, equal) = if partial {
+        (
+            quote! { ::core::cmp::PartialOrd::partial_cmp },
+            quote! { ::core::option::Option::Some(::core::cmp::Ordering::Equal) },
+        )
+    } else {
+        (
+            quote! { ::core::cmp::Ord::cmp },
+            quote! { ::core::cmp::Ordering::Equal },
+        )
+    };
+    if fields.iter().all(|field| cfg_of(field, cfgs).is_empty()) {
+        let self_tuple = quote! { ( #( &self.#fields, )* ) };
+        let other_tuple = quote! { ( #( &other.#fields, )* ) };
+        return quote! { #cmp(&#self_tuple, &#other_tuple) };
+    }
+    let steps = fields.iter().map(|field| {
+        let cfg = cfg_of(field, cfgs);
+        quote! {
+            #( #cfg )*
+            match #cmp(&self.#field, &other.#field) {
+                #equal => {}
+                ordering => return ordering,
+            }
+        }
+    });
+    quote! {
+        {
+            #( #steps )*
+            #equal
+        }
+    }
+}
+
+/// Moves the `compare_order(...)` fields to the front, keeping the rest in declaration order.
+fn prioritized<'a>(fields: &[&'a Member], order: &[Member]) -> Vec<&'a Member> {
+    let first = order
+        .iter()
+        .filter_map(|wanted| fields.iter().copied().find(|field| *field == wanted));
+    let rest = fields
+        .iter()
+        .copied()
+        .filter(|field| !order.contains(field));
+    first.chain(rest).collect()
+}
+
+/// Joins per-field checks into one boolean expression.
+///
+/// Ungated checks form a `&&` chain. Expression operands cannot carry `#[cfg]`, so as soon as a
+/// check is gated the chain becomes a block of early returns whose statements hold the attributes.
+fn all_checks(checks: Vec<(&[Attribute], TokenStream2)>) -> TokenStream2 {
+    if checks.is_empty() {
+        return quote! { true };
+    }
+    if checks.iter().all(|(cfg, _)| cfg.is_empty()) {
+        let checks = checks.iter().map(|(_, check)| check);
+        return quote! { #( (#checks) )&&* };
+    }
+    let checks = checks.iter().map(|(cfg, check)| {
+        quote! {
+            #( #cfg )*
+            if !(#check) {
+                return false;
+            }
+        }
+    });
+    quote! {
+        {
+            #( #checks )*
+            true
+        }
+    }
+}
+
+/// Collects the `#[cfg(...)]` attributes of the fields that carry any.
+///
+/// The macro sees fields before cfg-stripping, so checks on such fields are gated the same way.
+/// Positional fields are rejected: removing one would shift the indices of the fields after it.
+fn field_cfgs(fields: &Fields) -> syn::Result<Vec<(Member, Vec<Attribute>)>> {
+    let mut cfgs = Vec::new();
+    for (i, f) in fields.iter().enumerate() {
+        let attrs = f
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .cloned()
+            .collect::<Vec<_>>();
+        if let Some(attr) = attrs.first() {
+            if f.ident.is_none() {
+                return Err(Error::new(
+                    attr.span(),
+                    "`#[cfg]` on tuple fields is not supported; it would shift the indices of later fields",
+                ));
+            }
+            cfgs.push((field_member(i, f), attrs));
+        }
+    }
+    Ok(cfgs)
+}
+
+/// Collects the `#[serde(rename = "...")]` names of the fields that carry one.
+///
+/// Attributes that do not parse are skipped; serde itself reports them.
+fn serde_renames(fields: &Fields) -> Vec<(Member, String)> {
+    let mut renames = Vec::new();
+    for (i, f) in fields.iter().enumerate() {
+        let rename = f
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde"))
+            .filter_map(|attr| {
+                attr.parse_args_with(Punctuated::<syn::Meta, Comma>::parse_terminated)
+                    .ok()
+            })
+            .flatten()
+            .find_map(|meta| match meta {
+                syn::Meta::NameValue(nv) if nv.path.is_ident("rename") => match nv.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(ls), ..
+                    }) => Some(ls.value()),
+                    _ => None,
+                },
+                _ => None,
+            });
+        if let Some(rename) = rename {
+            renames.push((field_member(i, f), rename));
+        }
+    }
+    renames
+}
+
+/// Returns the `#[cfg(...)]` attributes gating a field, if any.
+fn cfg_of<'a>(field: &Member, cfgs: &'a [(Member, Vec<Attribute>)]) -> &'a [Attribute] {
+    cfgs.iter()
+        .find(|(m, _)| m == field)
+        .map_or(&[], |(_, attrs)| attrs.as_slice())
+}
+
+/// The two values a generated comparison reads its fields from.
+struct Sides {
+    lhs: TokenStream2,
+    rhs: TokenStream2,
+    /// Dereference each right-hand field once, for borrowed views.
+    deref_rhs: bool,
+}
+
+impl Sides {
+    /// `self` against `other`, as in the generated methods.
+    fn methods() -> Self {
+        Sides {
+            lhs: quote! { self },
+            rhs: quote! { other },
+            deref_rhs: false,
+        }
+    }
+}
+
+/// Builds the equality check for a single field of both sides.
+fn field_eq(field: &Member, rules: &[(Member, Compare)], sides: &Sides) -> TokenStream2 {
+    let Sides {
+        lhs,
+        rhs,
+        deref_rhs,
+    } = sides;
+    let lhs = quote! { #lhs.#field };
+    let rhs = if *deref_rhs {
+        quote! { (*#rhs.#field) }
+    } else {
+        quote! { #rhs.#field }
+    };
+    match rules.iter().find(|(m, _)| m == field) {
+        Some((_, compare)) => compare.expr(&lhs, &rhs),
+        None => quote! { #lhs == #rhs },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every string literal in `tokens`, in order.
+    fn string_literals(tokens: TokenStream2) -> Vec<String> {
+        tokens
+            .into_iter()
+            .flat_map(|tt| match tt {
+                TokenTree::Group(g) => string_literals(g.stream()),
+                TokenTree::Literal(lit) => match syn::parse_str::<LitStr>(&lit.to_string()) {
+                    Ok(s) => vec![s.value()],
+                    Err(_) => Vec::new(),
+                },
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Asserts that every `impl` block in `generated` is marked `#[automatically_derived]`.
+    fn assert_impls_marked(generated: TokenStream2) {
+        let file: syn::File = syn::parse2(generated).unwrap();
+        let impls = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Impl(imp) => Some(imp),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert!(!impls.is_empty());
+        for imp in impls {
+            assert!(
+                imp.attrs
+                    .iter()
+                    .any(|attr| attr.path().is_ident("automatically_derived")),
+                "unmarked impl: {}",
+                imp.to_token_stream()
+            );
+        }
+    }
+
+    #[test]
+    fn generated_impls_are_automatically_derived() {
+        let args: Args = parse_quote!(
+            ignore(ts),
+            as_trait,
+            method = "eq_meta",
+            derive_partial_eq,
+            key_wrapper = "ItemKey"
+        );
+        let input: DeriveInput = parse_quote! {
+            struct Item {
+                id: u64,
+                ts: i64,
+            }
+        };
+        assert_impls_marked(expand(args, input).unwrap().1);
+
+        let args: Args = parse_quote!(ignore(ts), as_trait);
+        let input: DeriveInput = parse_quote! {
+            enum Event {
+                Created { id: u64, ts: i64 },
+            }
+        };
+        assert_impls_marked(expand(args, input).unwrap().1);
+    }
+
+    #[test]
+    fn serde_renames_reads_rename_only() {
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                #[serde(default, rename = "userName")]
+                user_name: String,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                email: Option<String>,
+                #[serde(rename(serialize = "ser"))]
+                nested: u8,
+                plain: u8,
+            }
+        };
+        let Data::Struct(data) = &input.data else {
+            unreachable!()
+        };
+        let renames = serde_renames(&data.fields)
+            .into_iter()
+            .map(|(member, name)| (member_name(&member), name))
+            .collect::<Vec<_>>();
+        assert_eq!(renames, [("user_name".to_string(), "userName".to_string())]);
+    }
+
+    #[test]
+    fn serde_names_reports_the_wire_name_in_diff() {
+        let args: Args = parse_quote!(ignore(ts), diff = "diff_fields", serde_names);
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                #[serde(rename = "userName")]
+                user_name: String,
+                email: String,
+                ts: i64,
+            }
+        };
+        let (_, generated) = expand(args, input).unwrap();
+        let names = string_literals(generated);
+        assert!(names.iter().any(|name| name == "userName"));
+        assert!(names.iter().any(|name| name == "email"));
+        assert!(!names.iter().any(|name| name == "user_name"));
+    }
 }
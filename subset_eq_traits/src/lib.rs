@@ -0,0 +1,163 @@
+//! Companion items for the [`subset_eq`](https://docs.rs/subset_eq) attribute macro.
+//!
+//! Procedural macro crates can only export macros, so anything the generated code refers to
+//! by path lives here, along with helpers such as [`assert_subset_eq!`]. Add this crate next to
+//! `subset_eq` when using options such as `as_trait`.
+
+/// Subset equality implemented by `#[subset_eq(..., as_trait)]`.
+///
+/// Compares two values while skipping the fields excluded in the attribute, which makes the
+/// comparison usable behind a trait bound in generic code.
+pub trait SubsetEq {
+    /// Returns `true` when all compared fields of `self` and `other` are equal.
+    fn subset_eq(&self, other: &Self) -> bool;
+}
+
+/// Asserts that two values are equal according to a generated subset method.
+///
+/// `assert_subset_eq!(a, b, eq_meta)` panics when `a.eq_meta(&b)` is `false`, printing both
+/// values with `Debug`. Passing the name of a method generated with `diff = "..."` as a fourth
+/// argument additionally lists the differing fields, which is usually what you want to see:
+/// `assert_subset_eq!(a, b, eq_meta, diff_fields)`.
+///
+/// ```
+/// use subset_eq_traits::assert_subset_eq;
+///
+/// #[derive(Debug)]
+/// struct Item {
+///     id: u64,
+///     ts: i64,
+/// }
+///
+/// // Normally generated by `#[subset_eq(ignore(ts), method = "eq_meta")]`.
+/// impl Item {
+///     fn eq_meta(&self, other: &Self) -> bool {
+///         self.id == other.id
+///     }
+/// }
+///
+/// assert_subset_eq!(Item { id: 1, ts: 0 }, Item { id: 1, ts: 5 }, eq_meta);
+/// ```
+#[macro_export]
+macro_rules! assert_subset_eq {
+    ($left:expr, $right:expr, $method:ident $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !left.$method(right) {
+                    ::core::panic!(
+                        "assertion `left.{}(&right)` failed\n  left: {:?}\n right: {:?}",
+                        ::core::stringify!($method),
+                        left,
+                        right,
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $method:ident, $diff:ident $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !left.$method(right) {
+                    ::core::panic!(
+                        "assertion `left.{}(&right)` failed\ndiffering fields: {:?}\n  left: {:?}\n right: {:?}",
+                        ::core::stringify!($method),
+                        left.$diff(right),
+                        left,
+                        right,
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Declares a sealed trait for `#[subset_eq(..., sealed_trait = "...")]` to implement.
+///
+/// The trait gets a supertrait that cannot be named outside the module the macro is invoked in,
+/// so only the types listed after `for` can implement it; downstream crates can use it as a bound
+/// but not add impls of their own. Types are written as seen from that module. The seal lives in a
+/// private module named `__sealed`, so invoke the macro at most once per module.
+///
+/// ```
+/// mod cmp {
+///     subset_eq_traits::sealed! {
+///         /// Records comparable while ignoring their volatile fields.
+///         pub trait SubsetCmp {
+///             fn eq_meta(&self, other: &Self) -> bool;
+///         }
+///         for super::Item
+///     }
+/// }
+///
+/// struct Item {
+///     id: u64,
+///     ts: i64,
+/// }
+///
+/// // Normally generated by `#[subset_eq(ignore(ts), sealed_trait = "cmp::SubsetCmp", method = "eq_meta")]`.
+/// impl cmp::SubsetCmp for Item {
+///     fn eq_meta(&self, other: &Self) -> bool {
+///         self.id == other.id
+///     }
+/// }
+///
+/// fn main() {
+///     use cmp::SubsetCmp;
+///     assert!(Item { id: 1, ts: 0 }.eq_meta(&Item { id: 1, ts: 5 }));
+/// }
+/// ```
+#[macro_export]
+macro_rules! sealed {
+    (
+        $(#[$attr:meta])*
+        $vis:vis trait $name:ident { $($body:tt)* }
+        for $($ty:ty),+ $(,)?
+    ) => {
+        mod __sealed {
+            pub trait Sealed {}
+        }
+        $( impl __sealed::Sealed for $ty {} )+
+        $(#[$attr])*
+        $vis trait $name: __sealed::Sealed { $($body)* }
+    };
+}
+
+/// Per-type comparison strategy for methods generated with `profile_method = "..."`.
+///
+/// The generated `fn eq_with<P: ComparisonProfile>(&self, other: &Self) -> bool` sends every compared
+/// `f32` and `f64` field without a rule of its own through the profile, so one struct can be compared
+/// strictly or fuzzily by picking the type parameter: `a.eq_with::<Strict>(&b)` or
+/// `a.eq_with::<Fuzzy>(&b)`. Both methods default to `==`; override the ones that should differ.
+pub trait ComparisonProfile {
+    /// Compares two `f64` fields.
+    fn f64_eq(a: f64, b: f64) -> bool {
+        a == b
+    }
+
+    /// Compares two `f32` fields.
+    fn f32_eq(a: f32, b: f32) -> bool {
+        a == b
+    }
+}
+
+/// Compares floats with `==`, exactly like the plain subset method.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Strict;
+
+impl ComparisonProfile for Strict {}
+
+/// Compares floats within a small absolute tolerance: `1e-9` for `f64` and `1e-6` for `f32`.
+///
+/// NaN never compares equal, matching `approx(...)`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Fuzzy;
+
+impl ComparisonProfile for Fuzzy {
+    fn f64_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9
+    }
+
+    fn f32_eq(a: f32, b: f32) -> bool {
+        (a - b).abs() <= 1e-6
+    }
+}
@@ -0,0 +1,30 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, Copy)]
+#[subset_eq(allow_empty, method = "eq_marker", ne_method = "ne_marker")]
+struct Marker;
+
+#[derive(Debug)]
+#[subset_eq(allow_empty, diff = "diff_fields", method = "eq_empty")]
+struct Empty {}
+
+#[subset_eq(allow_empty, as_trait)]
+struct Unit();
+
+#[test]
+fn unit_structs_are_always_equal() {
+    assert!(Marker.eq_marker(&Marker));
+    assert!(!Marker.ne_marker(&Marker));
+}
+
+#[test]
+fn empty_named_structs_have_no_differences() {
+    assert!(Empty {}.eq_empty(&Empty {}));
+    assert!(Empty {}.diff_fields(&Empty {}).is_empty());
+}
+
+#[test]
+fn empty_tuple_structs_implement_the_trait() {
+    use subset_eq_traits::SubsetEq;
+    assert!(Unit().subset_eq(&Unit()));
+}
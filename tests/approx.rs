@@ -0,0 +1,64 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(approx(latitude = 1e-9, longitude = 1e-9), method = "eq_geo")]
+struct Location {
+    name: String,
+    latitude: f64,
+    longitude: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(approx(ratio = 0.5), method = "eq_rough")]
+struct Ratio {
+    ratio: f32,
+}
+
+fn location() -> Location {
+    Location {
+        name: "home".into(),
+        latitude: 52.52,
+        longitude: 13.405,
+    }
+}
+
+#[test]
+fn values_within_epsilon_are_equal() {
+    let a = location();
+    let mut b = a.clone();
+    b.latitude += 1e-12;
+    b.longitude -= 1e-12;
+    assert_ne!(a, b);
+    assert!(a.eq_geo(&b));
+}
+
+#[test]
+fn values_outside_epsilon_differ() {
+    let a = location();
+    let mut b = a.clone();
+    b.longitude += 1e-6;
+    assert!(!a.eq_geo(&b));
+}
+
+#[test]
+fn other_fields_use_partial_eq() {
+    let a = location();
+    let mut b = a.clone();
+    b.name = "work".into();
+    assert!(!a.eq_geo(&b));
+}
+
+#[test]
+fn nan_never_compares_approx_equal() {
+    let mut a = location();
+    a.latitude = f64::NAN;
+    let b = a.clone();
+    assert!(!a.eq_geo(&b));
+    assert!(!Ratio { ratio: f32::NAN }.eq_rough(&Ratio { ratio: 1.0 }));
+}
+
+#[test]
+fn epsilon_is_inclusive() {
+    assert!(Ratio { ratio: 1.0 }.eq_rough(&Ratio { ratio: 1.5 }));
+    assert!(!Ratio { ratio: 1.0 }.eq_rough(&Ratio { ratio: 1.75 }));
+}
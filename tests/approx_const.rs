@@ -0,0 +1,31 @@
+use subset_eq::subset_eq;
+
+const EPS: f64 = 1e-6;
+
+mod tolerances {
+    pub const COARSE: f64 = 0.5;
+}
+
+#[subset_eq(approx_const(lat = EPS, lon = EPS, alt = tolerances::COARSE), method = "eq_geo")]
+struct Position {
+    lat: f64,
+    lon: f64,
+    alt: f64,
+}
+
+fn position(lat: f64, lon: f64, alt: f64) -> Position {
+    Position { lat, lon, alt }
+}
+
+#[test]
+fn within_tolerance_is_equal() {
+    let a = position(48.0, 11.0, 500.0);
+    assert!(a.eq_geo(&position(48.0 + 1e-7, 11.0 - 1e-7, 500.4)));
+}
+
+#[test]
+fn outside_tolerance_differs() {
+    let a = position(48.0, 11.0, 500.0);
+    assert!(!a.eq_geo(&position(48.0 + 1e-5, 11.0, 500.0)));
+    assert!(!a.eq_geo(&position(48.0, 11.0, 501.0)));
+}
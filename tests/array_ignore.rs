@@ -0,0 +1,49 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[subset_eq(array_ignore(samples = [0, 7]), method = "eq_signal", diff = "diff_signal")]
+struct Signal {
+    channel: u8,
+    samples: [f64; 8],
+}
+
+#[derive(Debug, Clone, Copy)]
+#[subset_eq(array_ignore(0 = [0, 1]))]
+struct Header([u8; 2], u16);
+
+#[test]
+fn skips_ignored_indices() {
+    let a = Signal {
+        channel: 1,
+        samples: [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0],
+    };
+    let mut b = a;
+    b.samples[0] = 99.0;
+    b.samples[7] = -1.0;
+    assert_ne!(a, b);
+    assert!(a.eq_signal(&b));
+    assert!(a.diff_signal(&b).is_empty());
+}
+
+#[test]
+fn kept_indices_still_count() {
+    let a = Signal {
+        channel: 1,
+        samples: [0.0; 8],
+    };
+    for i in 1..7 {
+        let mut b = a;
+        b.samples[i] = 1.0;
+        assert!(!a.eq_signal(&b), "index {i} should be compared");
+        assert_eq!(a.diff_signal(&b), ["samples"]);
+    }
+    assert!(!a.eq_signal(&Signal { channel: 2, ..a }));
+}
+
+#[test]
+fn ignoring_every_index_leaves_the_array_out() {
+    let h = Header([1, 2], 3);
+    assert_eq!(h.0, [1, 2]);
+    assert!(h.eq_subset_ignoring(&Header([4, 5], 3)));
+    assert!(!Header([1, 2], 3).eq_subset_ignoring(&Header([1, 2], 4)));
+}
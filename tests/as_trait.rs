@@ -0,0 +1,49 @@
+use subset_eq::subset_eq;
+use subset_eq_traits::SubsetEq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), as_trait)]
+struct Event {
+    id: u64,
+    kind: String,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), as_trait, method = "eq_no_ts")]
+struct Sample {
+    value: f64,
+    ts: i64,
+}
+
+fn all_subset_eq<T: SubsetEq>(a: &[T], b: &[T]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.subset_eq(y))
+}
+
+#[test]
+fn trait_impl_ignores_fields() {
+    let a = Event {
+        id: 1,
+        kind: "created".into(),
+        ts: 10,
+    };
+    let mut b = a.clone();
+    b.ts = 20;
+    assert!(a.subset_eq(&b));
+    b.kind = "deleted".into();
+    assert!(!a.subset_eq(&b));
+}
+
+#[test]
+fn trait_usable_through_generic_bound() {
+    let a = vec![Sample { value: 1.5, ts: 0 }, Sample { value: 2.5, ts: 1 }];
+    let b = vec![Sample { value: 1.5, ts: 9 }, Sample { value: 2.5, ts: 9 }];
+    assert!(all_subset_eq(&a, &b));
+}
+
+#[test]
+fn explicit_method_is_kept_alongside_trait() {
+    let a = Sample { value: 1.0, ts: 0 };
+    let b = Sample { value: 1.0, ts: 5 };
+    assert_eq!(a.eq_no_ts(&b), a.subset_eq(&b));
+}
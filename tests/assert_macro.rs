@@ -0,0 +1,60 @@
+use std::panic;
+use subset_eq::subset_eq;
+use subset_eq_traits::assert_subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(ts), method = "eq_meta", diff = "diff_fields")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "foo".into(),
+        ts: 0,
+    }
+}
+
+fn panic_message(f: impl FnOnce() + panic::UnwindSafe) -> String {
+    let payload = panic::catch_unwind(f).expect_err("assertion should fail");
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn passes_when_only_ignored_fields_differ() {
+    let a = item();
+    let mut b = a.clone();
+    b.ts = 10;
+    assert_subset_eq!(a, b, eq_meta);
+    assert_subset_eq!(a, b, eq_meta, diff_fields);
+}
+
+#[test]
+fn failure_lists_differing_fields() {
+    let message = panic_message(|| {
+        let a = item();
+        let mut b = a.clone();
+        b.name = "bar".into();
+        assert_subset_eq!(a, b, eq_meta, diff_fields);
+    });
+    assert!(message.contains("assertion `left.eq_meta(&right)` failed"));
+    assert!(message.contains("differing fields: [\"name\"]"));
+}
+
+#[test]
+fn failure_without_diff_prints_both_values() {
+    let message = panic_message(|| {
+        let a = item();
+        let mut b = a.clone();
+        b.id = 2;
+        assert_subset_eq!(a, b, eq_meta);
+    });
+    assert!(message.contains("left: Item { id: 1"));
+    assert!(message.contains("right: Item { id: 2"));
+}
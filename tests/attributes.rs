@@ -0,0 +1,40 @@
+use std::mem;
+use subset_eq::subset_eq;
+
+/// Documented struct; the doc comment must survive re-emission.
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), method = "eq_meta")]
+#[derive(PartialEq, Default)]
+#[repr(C, align(16))]
+struct Item {
+    /// Identifier.
+    id: u64,
+    /// Cached value, skipped by the macro.
+    #[subset_skip]
+    #[allow(dead_code)]
+    cache: u32,
+    /// Timestamp.
+    ts: i64,
+}
+
+#[test]
+fn struct_attributes_are_kept() {
+    // `repr` survives, and each derive is applied exactly once (a duplicate would not compile).
+    assert_eq!(mem::align_of::<Item>(), 16);
+    let a = Item::default();
+    assert_eq!(a.clone(), a);
+    assert_eq!(
+        format!("{a:?}"),
+        "Item { id: 0, cache: 0, ts: 0 }".to_string()
+    );
+}
+
+#[test]
+fn skip_marker_is_the_only_field_attribute_removed() {
+    let a = Item::default();
+    let mut b = a.clone();
+    b.cache = 1;
+    b.ts = 1;
+    assert!(a.eq_meta(&b));
+    assert_ne!(a, b);
+}
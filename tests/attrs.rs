@@ -0,0 +1,29 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(
+    ignore(ts),
+    attrs("inline", "must_use", "doc(alias = \"same_meta\")"),
+    method = "eq_meta",
+    ne_method = "ne_meta"
+)]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), attrs("inline(always)"), method = "eq_dated")]
+enum Event {
+    Created { id: u64, ts: i64 },
+}
+
+#[test]
+fn attributed_methods_still_compare() {
+    let a = Item { id: 1, ts: 0 };
+    let b = Item { id: 1, ts: 5 };
+    assert!(a.eq_meta(&b));
+    assert!(!a.ne_meta(&b));
+    let e = Event::Created { id: 1, ts: 0 };
+    assert!(e.eq_dated(&Event::Created { id: 1, ts: 3 }));
+}
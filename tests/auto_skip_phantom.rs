@@ -0,0 +1,50 @@
+use std::marker::PhantomData;
+
+use subset_eq::subset_eq;
+
+/// A marker type without `PartialEq`, so comparing it would not compile.
+struct Meters;
+
+#[subset_eq(auto_skip_phantom, method = "eq_value")]
+struct Length {
+    value: f64,
+    unit: PhantomData<Meters>,
+}
+
+#[subset_eq(auto_skip_phantom, ignore(ts))]
+struct Tagged<T> {
+    id: u32,
+    ts: u64,
+    marker: std::marker::PhantomData<T>,
+    raw: ::core::marker::PhantomData<u32>,
+}
+
+#[test]
+fn skips_phantom_fields() {
+    let a = Length {
+        value: 1.5,
+        unit: PhantomData,
+    };
+    let b = Length {
+        value: 1.5,
+        unit: PhantomData,
+    };
+    assert!(a.eq_value(&b));
+    let _ = (a.unit, b.unit);
+}
+
+#[test]
+fn skips_phantom_fields_under_any_path() {
+    // `NoEq` has no `PartialEq`, so neither does `PhantomData<NoEq>`; no bound is required either.
+    struct NoEq;
+    let tagged = |id, ts| Tagged::<NoEq> {
+        id,
+        ts,
+        marker: PhantomData,
+        raw: PhantomData,
+    };
+    assert!(tagged(1, 0).eq_subset_ignoring(&tagged(1, 9)));
+    assert!(!tagged(1, 0).eq_subset_ignoring(&tagged(2, 0)));
+    let t = tagged(1, 0);
+    let _ = (t.ts, t.marker, t.raw);
+}
@@ -0,0 +1,62 @@
+use std::fmt;
+
+use subset_eq::subset_eq;
+
+/// A fixed-point amount whose scale is part of its value but not of its rendering.
+#[derive(Debug, PartialEq)]
+struct Decimal {
+    units: i64,
+    scale: u32,
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.units as f64 / 10f64.powi(self.scale as i32);
+        write!(f, "{value}")
+    }
+}
+
+#[subset_eq(by_display(amount), method = "eq_rendered")]
+struct Invoice {
+    id: u64,
+    amount: Decimal,
+}
+
+#[test]
+fn render_equal_values_compare_equal() {
+    let a = Invoice {
+        id: 1,
+        amount: Decimal {
+            units: 150,
+            scale: 2,
+        },
+    };
+    let b = Invoice {
+        id: 1,
+        amount: Decimal {
+            units: 1500,
+            scale: 3,
+        },
+    };
+    assert_ne!(a.amount, b.amount);
+    assert!(a.eq_rendered(&b));
+}
+
+#[test]
+fn render_unequal_values_differ() {
+    let a = Invoice {
+        id: 1,
+        amount: Decimal {
+            units: 150,
+            scale: 2,
+        },
+    };
+    let b = Invoice {
+        id: 1,
+        amount: Decimal {
+            units: 151,
+            scale: 2,
+        },
+    };
+    assert!(!a.eq_rendered(&b));
+}
@@ -0,0 +1,49 @@
+use std::rc::Rc;
+use std::sync::Arc;
+use subset_eq::subset_eq;
+
+struct Connection {
+    url: String,
+}
+
+#[derive(Clone)]
+#[subset_eq(by_ptr(handle), method = "eq_shared")]
+struct Session {
+    id: u64,
+    handle: Rc<Connection>,
+}
+
+#[derive(Clone)]
+#[subset_eq(by_ptr(0, 1))]
+struct Pair(Arc<str>, &'static str);
+
+fn session(handle: Rc<Connection>) -> Session {
+    Session { id: 1, handle }
+}
+
+#[test]
+fn clones_of_one_rc_are_equal() {
+    let handle = Rc::new(Connection { url: "db".into() });
+    let a = session(Rc::clone(&handle));
+    let b = session(handle);
+    assert!(a.eq_shared(&b));
+    assert_eq!(a.handle.url, "db");
+}
+
+#[test]
+fn equal_contents_in_separate_rcs_differ() {
+    let a = session(Rc::new(Connection { url: "db".into() }));
+    let b = session(Rc::new(Connection { url: "db".into() }));
+    assert!(!a.eq_shared(&b));
+    let c = Session { id: 2, ..a.clone() };
+    assert!(!a.eq_shared(&c));
+}
+
+#[test]
+fn works_for_arc_and_references() {
+    static TEXT: &str = "x";
+    let shared: Arc<str> = Arc::from("k");
+    let a = Pair(Arc::clone(&shared), TEXT);
+    assert!(a.eq_subset_ignoring(&a.clone()));
+    assert!(!a.eq_subset_ignoring(&Pair(Arc::from("k"), TEXT)));
+}
@@ -0,0 +1,39 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[subset_eq(
+    ignore(ts),
+    by_value,
+    method = "eq_meta",
+    ne_method = "ne_meta",
+    gen_macro
+)]
+struct Point {
+    x: i32,
+    y: i32,
+    ts: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[subset_eq(by_value, const_fn, method = "same_cell")]
+struct Cell(u8, u8);
+
+// Fails to compile if the const evaluation disagrees.
+const _: () = assert!(Cell(1, 2).same_cell(Cell(1, 2)));
+
+#[test]
+fn takes_both_sides_by_value() {
+    let a = Point { x: 1, y: 2, ts: 0 };
+    let b = Point { ts: 9, ..a };
+    assert!(a.eq_meta(b));
+    assert!(!a.eq_meta(Point { x: 3, ..b }));
+    assert!(a.ne_meta(Point { y: 0, ..a }));
+    assert!(point_matches!(a, b));
+    // Both values are `Copy`, so they stay usable afterwards.
+    assert_ne!(a, b);
+}
+
+#[test]
+fn const_method_is_callable_at_runtime() {
+    assert!(!Cell(1, 2).same_cell(Cell(2, 1)));
+}
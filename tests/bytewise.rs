@@ -0,0 +1,36 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C, packed)]
+#[subset_eq(bytewise, method = "same_bytes")]
+struct Header {
+    tag: u8,
+    len: u32,
+    flags: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+#[subset_eq(bytewise, method = "same_bytes", ne_method = "differs")]
+struct Pixel(u8, u8, u8, u8);
+
+#[test]
+fn compares_packed_struct_bytes() {
+    let a = Header {
+        tag: 1,
+        len: 512,
+        flags: 3,
+    };
+    assert!(a.same_bytes(&a.clone()));
+    assert!(!a.same_bytes(&Header { len: 513, ..a }));
+    assert!(!a.same_bytes(&Header { flags: 0, ..a }));
+}
+
+#[test]
+fn compares_padding_free_repr_c_struct() {
+    let p = Pixel(1, 2, 3, 255);
+    assert!(p.same_bytes(&Pixel(1, 2, 3, 255)));
+    assert!(p.differs(&Pixel(1, 2, 3, 0)));
+    // Agrees with the derived comparison for plain integers.
+    assert_eq!(p.same_bytes(&Pixel(0, 2, 3, 255)), p == Pixel(0, 2, 3, 255));
+}
@@ -0,0 +1,55 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(case_insensitive(name, email), ignore(last_login), method = "eq_ci")]
+struct User {
+    id: u64,
+    name: String,
+    email: &'static str,
+    last_login: i64,
+}
+
+fn user() -> User {
+    User {
+        id: 1,
+        name: "Alice".into(),
+        email: "Alice@Example.com",
+        last_login: 0,
+    }
+}
+
+#[test]
+fn ignores_ascii_case_of_listed_fields() {
+    let a = user();
+    let b = User {
+        name: "alice".into(),
+        email: "alice@example.com",
+        last_login: 9,
+        ..user()
+    };
+    assert!(a.eq_ci(&b));
+    assert_ne!(a, b);
+}
+
+#[test]
+fn still_compares_contents_and_other_fields() {
+    let a = user();
+    assert!(!a.eq_ci(&User {
+        name: "Alicia".into(),
+        ..user()
+    }));
+    assert!(!a.eq_ci(&User { id: 2, ..user() }));
+}
+
+#[test]
+fn folds_ascii_only() {
+    let a = User {
+        name: "ÉVA".into(),
+        ..user()
+    };
+    let b = User {
+        name: "éva".into(),
+        ..user()
+    };
+    assert!(!a.eq_ci(&b));
+}
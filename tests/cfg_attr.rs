@@ -0,0 +1,36 @@
+use subset_eq::subset_eq;
+
+// `any()` never holds, standing in for a disabled `feature = "serde"`; the derive is
+// dropped before anything resolves `Serialize`.
+#[derive(Debug)]
+#[cfg_attr(test, subset_eq(ignore(ts), method = "eq_meta"))]
+#[cfg_attr(all(), derive(Clone, PartialEq))]
+#[cfg_attr(any(), derive(Serialize))]
+struct Item {
+    id: u32,
+    ts: u64,
+}
+
+#[cfg_attr(test, subset_eq(ignore(ts), derive_partial_eq))]
+#[derive(Debug)]
+struct Event {
+    id: u32,
+    ts: u64,
+}
+
+#[test]
+fn conditional_attribute_and_derives_survive() {
+    let a = Item { id: 1, ts: 1 };
+    let b = Item { ts: 2, ..a.clone() };
+    assert!(a.eq_meta(&b));
+    assert!(a != b);
+    assert_eq!(format!("{a:?}"), "Item { id: 1, ts: 1 }");
+}
+
+#[test]
+fn conditional_partial_eq_applies() {
+    let event = Event { id: 1, ts: 1 };
+    assert_eq!(event.ts, 1);
+    assert_eq!(event, Event { id: 1, ts: 2 });
+    assert_ne!(Event { id: 1, ts: 1 }, Event { id: 2, ts: 1 });
+}
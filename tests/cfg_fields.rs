@@ -0,0 +1,177 @@
+use subset_eq::subset_eq;
+
+// Integration tests build with `cfg(test)`, so `cfg(test)` stands in for an enabled feature and
+// `cfg(not(test))` for a disabled one.
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(
+    ignore(ts),
+    method = "eq_meta",
+    diff = "diff_fields",
+    score = "similarity",
+    report = "RecordDiff",
+    field_eq_iter = "field_equality"
+)]
+struct Record {
+    id: u64,
+    #[cfg(test)]
+    enabled: String,
+    #[cfg(not(test))]
+    disabled: String,
+    ts: i64,
+}
+
+fn record() -> Record {
+    Record {
+        id: 1,
+        enabled: "on".into(),
+        ts: 0,
+    }
+}
+
+#[test]
+fn enabled_field_is_compared() {
+    let a = record();
+    let mut b = a.clone();
+    b.ts = 9;
+    assert!(a.eq_meta(&b));
+    b.enabled = "changed".into();
+    assert!(!a.eq_meta(&b));
+}
+
+#[test]
+fn outputs_skip_disabled_field() {
+    let a = record();
+    let mut b = a.clone();
+    b.enabled = "changed".into();
+    assert_eq!(a.diff_fields(&b), vec!["enabled"]);
+    assert_eq!(a.similarity(&b), 0.5);
+    let report = a.subset_diff(&b);
+    assert!(report.enabled_changed);
+    assert!(!report.id_changed);
+    assert!(!report.is_equal());
+    let pairs: Vec<_> = a.field_equality(&b).collect();
+    assert_eq!(pairs, vec![("id", true), ("enabled", false)]);
+}
+
+#[derive(Debug, Clone, Copy)]
+#[subset_eq(ignore(ts), method = "eq_meta", const_fn)]
+struct Counter {
+    #[cfg(not(test))]
+    label: &'static str,
+    value: u32,
+    ts: i64,
+}
+
+#[test]
+fn const_fn_with_disabled_field() {
+    let a = Counter { value: 1, ts: 0 };
+    let b = Counter { value: 1, ts: 3 };
+    assert_ne!(a.ts, b.ts);
+    assert!(a.eq_meta(&b));
+    assert!(!a.eq_meta(&Counter { value: 2, ts: 0 }));
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), method = "eq_meta")]
+enum Event {
+    Created {
+        id: u64,
+        #[cfg(test)]
+        enabled: u8,
+        #[cfg(not(test))]
+        disabled: u8,
+        ts: i64,
+    },
+    Deleted(u64),
+}
+
+#[test]
+fn enum_variants_honor_cfg() {
+    let a = Event::Created {
+        id: 1,
+        enabled: 2,
+        ts: 0,
+    };
+    assert!(a.eq_meta(&Event::Created {
+        id: 1,
+        enabled: 2,
+        ts: 5,
+    }));
+    assert!(!a.eq_meta(&Event::Created {
+        id: 1,
+        enabled: 3,
+        ts: 0,
+    }));
+    assert!(Event::Deleted(1).eq_meta(&Event::Deleted(1)));
+    if let Event::Created { ts, .. } = a {
+        assert_eq!(ts, 0);
+    }
+}
+
+// Options may name a field whichever way its `cfg` goes: the macro sees the field as written, and
+// checks on a disabled field are compiled out with it.
+fn close(a: &f64, b: &f64) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(
+    ignore(enabled_meta, disabled_meta),
+    with(enabled_score = "close", disabled_score = "close"),
+    compare_order(disabled_score, id),
+    method = "eq_meta"
+)]
+struct Gated {
+    id: u64,
+    #[cfg(test)]
+    enabled_meta: i64,
+    #[cfg(not(test))]
+    disabled_meta: i64,
+    #[cfg(test)]
+    enabled_score: f64,
+    #[cfg(not(test))]
+    disabled_score: f64,
+}
+
+#[test]
+fn options_accept_enabled_and_disabled_fields() {
+    let a = Gated {
+        id: 1,
+        enabled_meta: 0,
+        enabled_score: 0.5,
+    };
+    let b = Gated {
+        id: 1,
+        enabled_meta: 9,
+        enabled_score: 0.5 + 1e-9,
+    };
+    assert!(a.eq_meta(&b));
+    assert!(!a.eq_meta(&Gated { id: 2, ..b.clone() }));
+    assert!(!a.eq_meta(&Gated {
+        enabled_score: 0.6,
+        ..b.clone()
+    }));
+    assert_ne!(a.enabled_meta, b.enabled_meta);
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(only(id, disabled), method = "eq_identity")]
+struct Whitelisted {
+    id: u64,
+    #[cfg(not(test))]
+    disabled: String,
+    name: String,
+}
+
+#[test]
+fn only_accepts_a_disabled_field() {
+    let a = Whitelisted {
+        id: 1,
+        name: "a".into(),
+    };
+    assert!(a.eq_identity(&Whitelisted {
+        id: 1,
+        name: "b".into(),
+    }));
+    assert_eq!(a.name, "a");
+}
@@ -0,0 +1,84 @@
+use subset_eq::subset_eq;
+
+#[derive(Clone)]
+#[subset_eq(
+    ignore(updated_at, version),
+    method = "eq_core",
+    changed_method = "meta_changed"
+)]
+struct Record {
+    id: u64,
+    body: String,
+    updated_at: i64,
+    version: u32,
+}
+
+#[subset_eq(changed_method = "anything_ignored_changed")]
+struct Plain {
+    id: u64,
+}
+
+struct NoEq;
+
+#[subset_eq(ignore(version), changed_method = "version_changed")]
+struct Handler {
+    id: u64,
+    version: u32,
+    #[subset_skip]
+    callback: NoEq,
+}
+
+fn record() -> Record {
+    Record {
+        id: 1,
+        body: "hello".into(),
+        updated_at: 0,
+        version: 1,
+    }
+}
+
+#[test]
+fn reports_any_differing_ignored_field() {
+    let a = record();
+    let mut b = a.clone();
+    assert!(!a.meta_changed(&b));
+    b.version = 2;
+    assert!(a.meta_changed(&b));
+    b.version = 1;
+    b.updated_at = 5;
+    assert!(a.meta_changed(&b));
+    assert!(a.eq_core(&b));
+}
+
+#[test]
+fn ignores_the_compared_fields() {
+    let a = record();
+    let mut b = a.clone();
+    b.body = "world".into();
+    assert!(!a.meta_changed(&b));
+    assert_ne!(a.id + 1, b.id);
+}
+
+#[test]
+fn is_always_false_without_ignored_fields() {
+    let (a, b) = (Plain { id: 1 }, Plain { id: 2 });
+    assert!(!a.anything_ignored_changed(&b));
+    assert_ne!(a.id, b.id);
+}
+
+#[test]
+fn skipped_fields_are_not_checked() {
+    let a = Handler {
+        id: 1,
+        version: 1,
+        callback: NoEq,
+    };
+    let b = Handler {
+        version: 2,
+        callback: NoEq,
+        ..a
+    };
+    assert!(a.version_changed(&b));
+    assert!(!a.version_changed(&a));
+    let _ = (a.id, &a.callback, &b.callback);
+}
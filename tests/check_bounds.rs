@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), check_bounds, approx(weight = 1e-9), method = "eq_checked")]
+struct Catalog {
+    items: HashMap<String, Vec<u32>>,
+    weight: f64,
+    ts: i64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(check_bounds, method = "eq_checked")]
+struct Tagged<'a, T> {
+    label: &'a str,
+    value: T,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), check_bounds, method = "eq_checked")]
+struct Node {
+    value: u32,
+    next: Option<Box<Self>>,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(check_bounds, method = "eq_checked")]
+struct List<T> {
+    value: T,
+    next: Option<Box<Self>>,
+}
+
+#[test]
+fn comparable_fields_pass_the_assertions() {
+    let a = Catalog {
+        items: HashMap::from([("a".to_string(), vec![1, 2])]),
+        weight: 1.0,
+        ts: 0,
+    };
+    let mut b = a.clone();
+    b.ts = 7;
+    assert!(a.eq_checked(&b));
+    b.items.insert("b".into(), vec![]);
+    assert!(!a.eq_checked(&b));
+}
+
+#[test]
+fn generic_fields_are_left_to_the_method_bounds() {
+    let a = Tagged {
+        label: "x",
+        value: 1,
+    };
+    assert!(a.eq_checked(&a.clone()));
+    assert!(!a.eq_checked(&Tagged {
+        label: "y",
+        value: 1,
+    }));
+}
+
+#[test]
+fn self_typed_fields_are_checked_as_the_struct() {
+    let leaf = Node {
+        value: 2,
+        next: None,
+        ts: 0,
+    };
+    let a = Node {
+        value: 1,
+        next: Some(Box::new(leaf.clone())),
+        ts: 0,
+    };
+    let b = Node { ts: 9, ..a.clone() };
+    assert!(a.eq_checked(&b));
+    assert!(!a.eq_checked(&leaf));
+
+    let list = List {
+        value: 1,
+        next: Some(Box::new(List {
+            value: 2,
+            next: None,
+        })),
+    };
+    assert!(list.eq_checked(&list.clone()));
+}
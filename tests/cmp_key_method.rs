@@ -0,0 +1,51 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), cmp_key_method = "sort_key", ord_method = "cmp_stable")]
+struct Item {
+    name: String,
+    id: u64,
+    ts: i64,
+}
+
+fn item(name: &str, id: u64, ts: i64) -> Item {
+    Item {
+        name: name.into(),
+        id,
+        ts,
+    }
+}
+
+#[test]
+fn keys_hold_the_compared_fields() {
+    let key: (String, u64) = item("a", 1, 9).sort_key();
+    assert_eq!(key, ("a".to_string(), 1));
+}
+
+#[test]
+fn sorting_keys_ignores_the_ignored_field() {
+    let items = [
+        item("b", 1, 0),
+        item("a", 2, 5),
+        item("a", 1, 9),
+        item("a", 1, 3),
+    ];
+    let mut keys = items.iter().map(Item::sort_key).collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(
+        keys,
+        [
+            ("a".to_string(), 1),
+            ("a".to_string(), 1),
+            ("a".to_string(), 2),
+            ("b".to_string(), 1),
+        ]
+    );
+    assert_eq!(items[2].sort_key(), items[3].sort_key());
+    assert_ne!(items[2].ts, items[3].ts);
+}
+
+#[test]
+fn key_order_matches_ord_method() {
+    let (a, b) = (item("a", 2, 0), item("b", 1, 0));
+    assert_eq!(a.sort_key().cmp(&b.sort_key()), a.cmp_stable(&b));
+}
@@ -0,0 +1,77 @@
+use std::cell::Cell;
+use subset_eq::subset_eq;
+
+thread_local! {
+    static PAYLOAD_CHECKS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn tracked_eq(a: &String, b: &String) -> bool {
+    PAYLOAD_CHECKS.with(|c| c.set(c.get() + 1));
+    a == b
+}
+
+fn payload_checks() -> usize {
+    PAYLOAD_CHECKS.with(Cell::get)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(
+    ignore(ts),
+    with(payload = "tracked_eq"),
+    compare_order(id),
+    method = "eq_fast"
+)]
+struct Record {
+    payload: String,
+    name: String,
+    id: u64,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), compare_order(c, a), method = "eq_ordered")]
+struct Triple {
+    a: u8,
+    b: u8,
+    c: u8,
+    ts: i64,
+}
+
+fn record(id: u64) -> Record {
+    Record {
+        payload: "large".into(),
+        name: "foo".into(),
+        id,
+        ts: 0,
+    }
+}
+
+#[test]
+fn listed_fields_short_circuit_first() {
+    let before = payload_checks();
+    assert!(!record(1).eq_fast(&record(2)));
+    assert_eq!(payload_checks(), before);
+
+    assert!(record(1).eq_fast(&record(1)));
+    assert_eq!(payload_checks(), before + 1);
+}
+
+#[test]
+fn result_matches_across_permutations() {
+    let base = Triple {
+        a: 0,
+        b: 0,
+        c: 0,
+        ts: 0,
+    };
+    for bits in 0u8..8 {
+        let other = Triple {
+            a: bits & 1,
+            b: (bits >> 1) & 1,
+            c: (bits >> 2) & 1,
+            ts: i64::from(bits),
+        };
+        let expected = other.a == 0 && other.b == 0 && other.c == 0;
+        assert_eq!(base.eq_ordered(&other), expected, "bits {bits:03b}");
+    }
+}
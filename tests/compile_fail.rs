@@ -0,0 +1,8 @@
+//! Compile-fail snapshots for the macro's diagnostics, so a refactor of the argument parser
+//! cannot silently degrade the messages or their spans. Run with `TRYBUILD=overwrite` to rewrite
+//! the `.stderr` files after an intentional change.
+
+#[test]
+fn ui() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,67 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), conditional(debug_info, cache), method = "eq_cond")]
+struct Item {
+    id: u64,
+    debug_info: String,
+    cache: u32,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(runtime_mask, conditional(1))]
+struct Point(i64, i64, i64);
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        debug_info: "a".into(),
+        cache: 7,
+        ts: 0,
+    }
+}
+
+#[test]
+fn each_flag_skips_its_field() {
+    let a = item();
+    let debug = Item {
+        debug_info: "b".into(),
+        ..item()
+    };
+    assert!(!a.eq_cond(&debug, false, false));
+    assert!(a.eq_cond(&debug, true, false));
+    assert!(!a.eq_cond(&debug, false, true));
+
+    let cache = Item { cache: 8, ..item() };
+    assert!(!a.eq_cond(&cache, false, false));
+    assert!(!a.eq_cond(&cache, true, false));
+    assert!(a.eq_cond(&cache, false, true));
+
+    let both = Item {
+        debug_info: "b".into(),
+        cache: 8,
+        ts: 5,
+        ..item()
+    };
+    assert!(a.eq_cond(&both, true, true));
+    assert_ne!(a, both);
+}
+
+#[test]
+fn other_fields_are_always_compared() {
+    let a = item();
+    let b = Item { id: 2, ..item() };
+    assert!(!a.eq_cond(&b, true, true));
+}
+
+#[test]
+fn flags_follow_the_mask() {
+    let a = Point(1, 2, 3);
+    let b = Point(1, 5, 6);
+    assert!(!a.eq_subset_ignoring(&b, 0, true));
+    assert!(a.eq_subset_ignoring(&b, Point::FIELD_2, true));
+    assert!(!a.eq_subset_ignoring(&b, Point::FIELD_2, false));
+    assert!(a.eq_subset_ignoring(&b, Point::FIELD_1 | Point::FIELD_2, false));
+    assert_ne!(a, b);
+}
@@ -0,0 +1,44 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[subset_eq(ignore(ts), const_fn, method = "eq_meta", ne_method = "ne_meta")]
+struct Version {
+    major: u32,
+    minor: u32,
+    ts: u64,
+}
+
+const X: Version = Version {
+    major: 1,
+    minor: 2,
+    ts: 100,
+};
+const Y: Version = Version {
+    major: 1,
+    minor: 2,
+    ts: 200,
+};
+const Z: Version = Version {
+    major: 2,
+    minor: 0,
+    ts: 100,
+};
+
+const _: bool = X.eq_meta(&Y);
+// Fails to compile if the const evaluation disagrees.
+const _: () = assert!(X.eq_meta(&Y));
+const _: () = assert!(X.ne_meta(&Z));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[subset_eq(const_fn)]
+struct Flags(bool, char);
+
+const _: () = assert!(Flags(true, 'a').eq_subset_ignoring(&Flags(true, 'a')));
+
+#[test]
+fn const_method_is_callable_at_runtime() {
+    let (x, z) = (X, Z);
+    assert!(!x.eq_meta(&z));
+    assert!(x.ne_meta(&z));
+    assert!(!Flags(true, 'a').eq_subset_ignoring(&Flags(false, 'a')));
+}
@@ -0,0 +1,16 @@
+use subset_eq::subset_eq;
+
+// The expansion is printed while this file compiles; behavior must match a build without the flag.
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), debug_print, method = "eq_meta")]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+#[test]
+fn flag_does_not_change_behavior() {
+    let a = Item { id: 1, ts: 0 };
+    assert!(a.eq_meta(&Item { id: 1, ts: 5 }));
+    assert!(!a.eq_meta(&Item { id: 2, ts: 0 }));
+}
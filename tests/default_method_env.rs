@@ -0,0 +1,40 @@
+//! Needs `SUBSET_EQ_DEFAULT_METHOD=same_as`, which would rename the method of every other test, so
+//! it is left out of `cargo test` and run on its own in CI:
+//!
+//! ```sh
+//! SUBSET_EQ_DEFAULT_METHOD=same_as cargo test --test default_method_env
+//! ```
+
+use subset_eq::subset_eq;
+
+// Without the variable, this fails with a hint instead of a missing `same_as`.
+const DEFAULT_METHOD: &str = env!(
+    "SUBSET_EQ_DEFAULT_METHOD",
+    "run with `SUBSET_EQ_DEFAULT_METHOD=same_as`"
+);
+
+#[subset_eq(ignore(ts))]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+#[subset_eq(ignore(ts), method = "eq_meta")]
+struct Explicit {
+    id: u64,
+    ts: i64,
+}
+
+#[test]
+fn the_variable_renames_the_default_method() {
+    assert_eq!(DEFAULT_METHOD, "same_as");
+    let item = |ts| Item { id: 1, ts };
+    assert!(item(1).same_as(&item(2)));
+    assert!(!item(1).same_as(&Item { id: 2, ts: 1 }));
+}
+
+#[test]
+fn an_explicit_method_is_untouched() {
+    let explicit = |ts| Explicit { id: 1, ts };
+    assert!(explicit(1).eq_meta(&explicit(2)));
+}
@@ -0,0 +1,38 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(default_wildcard(priority, color), method = "matches_partial")]
+struct Filter {
+    name: String,
+    priority: u8,
+    color: String,
+}
+
+fn filter(priority: u8, color: &str) -> Filter {
+    Filter {
+        name: "f".into(),
+        priority,
+        color: color.into(),
+    }
+}
+
+#[test]
+fn default_values_match_anything() {
+    assert!(filter(0, "red").matches_partial(&filter(5, "red")));
+    assert!(filter(5, "red").matches_partial(&filter(0, "red")));
+    assert!(filter(5, "").matches_partial(&filter(5, "blue")));
+    assert!(filter(0, "").matches_partial(&filter(3, "green")));
+}
+
+#[test]
+fn set_values_are_compared() {
+    assert!(filter(5, "red").matches_partial(&filter(5, "red")));
+    assert!(!filter(5, "red").matches_partial(&filter(4, "red")));
+    assert!(!filter(5, "red").matches_partial(&filter(5, "blue")));
+}
+
+#[test]
+fn other_fields_are_compared_as_usual() {
+    let mut other = filter(0, "");
+    other.name = "g".into();
+    assert!(!filter(0, "").matches_partial(&other));
+}
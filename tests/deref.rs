@@ -0,0 +1,45 @@
+use std::rc::Rc;
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(deref(payload), ignore(ts), method = "eq_payload")]
+struct Message {
+    payload: Rc<String>,
+    ts: i64,
+}
+
+#[derive(Debug)]
+#[subset_eq(deref(name), other = "LabelRef", method = "matches_ref")]
+struct Label {
+    name: Box<str>,
+}
+
+struct LabelRef {
+    name: &'static str,
+}
+
+#[test]
+fn compares_pointed_to_contents() {
+    let a = Message {
+        payload: Rc::new("hello".into()),
+        ts: 0,
+    };
+    let b = Message {
+        payload: Rc::new("hello".into()),
+        ts: 5,
+    };
+    assert!(!Rc::ptr_eq(&a.payload, &b.payload));
+    assert!(a.eq_payload(&b));
+    let c = Message {
+        payload: Rc::new("bye".into()),
+        ts: 0,
+    };
+    assert!(!a.eq_payload(&c));
+}
+
+#[test]
+fn meets_across_pointer_types() {
+    let label = Label { name: "red".into() };
+    assert!(label.matches_ref(&LabelRef { name: "red" }));
+    assert!(!label.matches_ref(&LabelRef { name: "blue" }));
+}
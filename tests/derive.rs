@@ -0,0 +1,84 @@
+use subset_eq::SubsetEq;
+
+#[derive(Debug, Clone, PartialEq, Eq, SubsetEq)]
+#[subset(method = "eq_stable", ne_method = "ne_stable")]
+struct Item {
+    id: u64,
+    name: String,
+    #[subset(ignore)]
+    updated_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, SubsetEq)]
+struct Defaulted {
+    id: u64,
+    /// Documented and skipped.
+    #[subset(ignore)]
+    cache_token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, SubsetEq)]
+#[subset(ignore(0), method = "eq_tail")]
+struct Tuple(u8, #[subset(ignore)] u8, u8);
+
+#[derive(Debug, Clone, PartialEq, SubsetEq)]
+#[subset(method = "eq_shape")]
+enum Shape {
+    Circle {
+        radius: u32,
+        #[subset(ignore)]
+        label: String,
+    },
+    Square(u32),
+}
+
+#[test]
+fn marked_field_is_ignored() {
+    let a = Item {
+        id: 1,
+        name: "a".into(),
+        updated_at: 0,
+    };
+    let mut b = a.clone();
+    b.updated_at = 100;
+    assert_ne!(a, b);
+    assert!(a.eq_stable(&b));
+    assert!(!a.ne_stable(&b));
+    b.name = "b".into();
+    assert!(!a.eq_stable(&b));
+    assert!(a.ne_stable(&b));
+}
+
+#[test]
+fn default_method_name_without_options() {
+    let a = Defaulted {
+        id: 1,
+        cache_token: "x".into(),
+    };
+    let mut b = a.clone();
+    b.cache_token = "y".into();
+    assert!(a.eq_subset_ignoring(&b));
+    b.id = 2;
+    assert!(!a.eq_subset_ignoring(&b));
+}
+
+#[test]
+fn markers_combine_with_ignore_list() {
+    assert!(Tuple(1, 2, 3).eq_tail(&Tuple(9, 8, 3)));
+    assert!(!Tuple(1, 2, 3).eq_tail(&Tuple(1, 2, 4)));
+}
+
+#[test]
+fn enum_variant_fields_can_be_ignored() {
+    let a = Shape::Circle {
+        radius: 2,
+        label: "a".into(),
+    };
+    let b = Shape::Circle {
+        radius: 2,
+        label: "b".into(),
+    };
+    assert!(a.eq_shape(&b));
+    assert!(!a.eq_shape(&Shape::Square(2)));
+    assert!(Shape::Square(2).eq_shape(&Shape::Square(2)));
+}
@@ -0,0 +1,44 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), derive_partial_eq)]
+struct Item {
+    id: u64,
+    name: String,
+    ts: f64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), derive_partial_eq, derive_eq, method = "eq_meta")]
+struct Key<T> {
+    id: T,
+    ts: i64,
+}
+
+#[test]
+fn eq_operator_ignores_excluded_fields() {
+    let a = Item {
+        id: 1,
+        name: "foo".into(),
+        ts: 0.5,
+    };
+    let mut b = a.clone();
+    b.ts = 9.0;
+    assert_eq!(a, b);
+    b.name = "bar".into();
+    assert_ne!(a, b);
+}
+
+#[test]
+fn eq_flag_and_method_together() {
+    let a = Key { id: 1u8, ts: 0 };
+    let b = Key { id: 1u8, ts: 0 };
+    assert!(a.eq_meta(&b));
+    assert_eq!(a, b);
+
+    fn assert_eq_impl<T: Eq>() {}
+    assert_eq_impl::<Key<u8>>();
+    let c = Key { id: 1u8, ts: 7 };
+    assert_ne!(a.ts, c.ts);
+    assert_eq!(a, c);
+}
@@ -0,0 +1,51 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), derive_partial_eq, derive_partial_ord)]
+struct Item {
+    priority: u8,
+    name: &'static str,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[subset_eq(derive_partial_eq, derive_partial_ord)]
+struct Wrapper<T>(T);
+
+fn item(priority: u8, name: &'static str, ts: i64) -> Item {
+    Item { priority, name, ts }
+}
+
+#[test]
+fn sorting_ignores_the_excluded_field() {
+    let mut items: Vec<Item> = [
+        item(2, "b", 0),
+        item(1, "z", 9),
+        item(2, "a", 5),
+        item(1, "z", 1),
+    ]
+    .to_vec();
+    items.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let order = items
+        .iter()
+        .map(|i| (i.priority, i.name))
+        .collect::<Vec<_>>();
+    assert_eq!(order, [(1, "z"), (1, "z"), (2, "a"), (2, "b")]);
+    // The sort is stable and `ts` plays no part, so equal items keep their original order.
+    assert_eq!(items[0].ts, 9);
+    assert_eq!(items[1].ts, 1);
+}
+
+#[test]
+fn operators_use_the_subset() {
+    assert!(item(1, "a", 9) < item(2, "a", 0));
+    assert!(item(1, "b", 0) > item(1, "a", 9));
+    assert!(item(1, "a", 0) <= item(1, "a", 9));
+    assert_eq!(item(1, "a", 0), item(1, "a", 9));
+}
+
+#[test]
+fn generic_fields_need_partial_ord() {
+    assert!(Wrapper(1.5) < Wrapper(2.0));
+    assert_eq!(Wrapper(f64::NAN).partial_cmp(&Wrapper(1.0)), None);
+}
@@ -0,0 +1,52 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(ts), diff = "diff_fields")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+    owner: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(ts), method = "eq_no_ts", diff = "diff_fields")]
+struct Both {
+    id: u64,
+    ts: i64,
+}
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "foo".into(),
+        ts: 0,
+        owner: "me".into(),
+    }
+}
+
+#[test]
+fn equal_records_have_no_diff() {
+    let a = item();
+    let mut b = a.clone();
+    b.ts = 42;
+    assert!(a.diff_fields(&b).is_empty());
+}
+
+#[test]
+fn diff_lists_changed_fields_in_declaration_order() {
+    let a = item();
+    let mut b = a.clone();
+    b.owner = "you".into();
+    b.id = 2;
+    b.ts = 7;
+    assert_eq!(a.diff_fields(&b), vec!["id", "owner"]);
+}
+
+#[test]
+fn diff_is_generated_alongside_method() {
+    let a = Both { id: 1, ts: 0 };
+    let b = Both { id: 2, ts: 0 };
+    assert!(!a.eq_no_ts(&b));
+    assert_eq!(a.diff_fields(&b), vec!["id"]);
+}
@@ -0,0 +1,88 @@
+use subset_eq::subset_eq;
+use subset_eq_traits::SubsetEq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), method = "eq_core", ne_method = "ne_core")]
+enum Event {
+    Created { id: u64, ts: i64 },
+    Renamed { id: u64, name: String, ts: i64 },
+    Moved(u64, #[subset_skip] i64),
+    Cleared,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(1), as_trait)]
+enum Shape<T> {
+    Point(T, &'static str),
+    Empty,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(only(id), method = "eq_id")]
+enum Keyed {
+    Created { id: u64, ts: i64 },
+    Renamed { name: String },
+}
+
+#[test]
+fn same_variant_ignores_fields() {
+    let a = Event::Renamed {
+        id: 1,
+        name: "a".into(),
+        ts: 0,
+    };
+    let b = Event::Renamed {
+        id: 1,
+        name: "a".into(),
+        ts: 99,
+    };
+    assert_ne!(a, b);
+    assert!(a.eq_core(&b));
+    assert!(!a.ne_core(&b));
+}
+
+#[test]
+fn same_variant_detects_compared_change() {
+    let a = Event::Created { id: 1, ts: 0 };
+    let b = Event::Created { id: 2, ts: 0 };
+    assert!(!a.eq_core(&b));
+}
+
+#[test]
+fn different_variants_never_match() {
+    let a = Event::Created { id: 1, ts: 0 };
+    let b = Event::Renamed {
+        id: 1,
+        name: "a".into(),
+        ts: 0,
+    };
+    assert!(!a.eq_core(&b));
+    assert!(!Event::Cleared.eq_core(&a));
+}
+
+#[test]
+fn unit_variants_equal_themselves() {
+    assert!(Event::Cleared.eq_core(&Event::Cleared));
+}
+
+#[test]
+fn tuple_variants_honor_skip_markers() {
+    assert!(Event::Moved(1, 10).eq_core(&Event::Moved(1, 20)));
+    assert!(!Event::Moved(1, 10).eq_core(&Event::Moved(2, 10)));
+}
+
+#[test]
+fn generic_enum_implements_trait() {
+    assert!(Shape::Point(1, "a").subset_eq(&Shape::Point(1, "b")));
+    assert!(!Shape::Point(1, "a").subset_eq(&Shape::Point(2, "a")));
+    assert!(Shape::<u8>::Empty.subset_eq(&Shape::Empty));
+}
+
+#[test]
+fn only_names_a_field_of_some_variant() {
+    let a = Keyed::Created { id: 1, ts: 0 };
+    assert!(a.eq_id(&Keyed::Created { id: 1, ts: 5 }));
+    assert!(!a.eq_id(&Keyed::Created { id: 2, ts: 0 }));
+    let renamed = Keyed::Renamed { name: "a".into() };
+    assert!(renamed.eq_id(&Keyed::Renamed { name: "b".into() }));
+}
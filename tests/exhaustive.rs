@@ -0,0 +1,46 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), exhaustive)]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+    #[cfg(test)]
+    tag: u8,
+    #[cfg(not(test))]
+    release_only: u8,
+}
+
+#[subset_eq(only(id), exhaustive, method_suffix = "_v2")]
+struct Tagged {
+    id: u64,
+    label: String,
+}
+
+#[test]
+fn lists_the_compared_fields() {
+    assert_eq!(Item::SUBSET_EQ_FIELDS, ["id", "name", "tag"]);
+}
+
+#[test]
+fn keeps_the_main_method() {
+    let item = |ts| Item {
+        id: 1,
+        name: "a".into(),
+        ts,
+        tag: 0,
+    };
+    assert!(item(0).eq_subset_ignoring(&item(1)));
+    assert_eq!(item(2).ts, 2);
+}
+
+#[test]
+fn takes_the_method_suffix() {
+    assert_eq!(Tagged::SUBSET_EQ_FIELDS_V2, ["id"]);
+    let t = Tagged {
+        id: 1,
+        label: "x".into(),
+    };
+    assert!(t.eq_subset_ignoring_v2(&t));
+    assert_eq!(t.label, "x");
+}
@@ -0,0 +1,44 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), field_eq_iter = "field_equality")]
+struct Item {
+    id: u64,
+    name: String,
+    tags: Vec<String>,
+    ts: i64,
+}
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "foo".into(),
+        tags: vec!["a".into()],
+        ts: 0,
+    }
+}
+
+#[test]
+fn yields_every_compared_field_in_order() {
+    let a = item();
+    let mut b = a.clone();
+    b.name = "bar".into();
+    b.ts = 5;
+    let pairs: Vec<_> = a.field_equality(&b).collect();
+    assert_eq!(pairs, vec![("id", true), ("name", false), ("tags", true)]);
+}
+
+#[test]
+fn supports_filtering_and_counting() {
+    let a = item();
+    let mut b = a.clone();
+    b.id = 2;
+    b.tags.clear();
+    let differing: Vec<_> = a
+        .field_equality(&b)
+        .filter(|(_, equal)| !equal)
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(differing, vec!["id", "tags"]);
+    assert_eq!(a.field_equality(&a).filter(|(_, equal)| *equal).count(), 3);
+}
@@ -0,0 +1,120 @@
+use subset_eq::subset_eq;
+
+// The same fields declared in two orders; equality must not depend on it.
+#[derive(Debug, Clone)]
+#[subset_eq(
+    ignore(ts),
+    method = "eq_meta",
+    diff = "diff_fields",
+    try_method = "check_eq",
+    field_eq_iter = "field_equality"
+)]
+struct Forward {
+    id: u64,
+    name: String,
+    tag: char,
+    ts: i64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(
+    ignore(ts),
+    method = "eq_meta",
+    diff = "diff_fields",
+    try_method = "check_eq"
+)]
+struct Reversed {
+    ts: i64,
+    tag: char,
+    name: String,
+    id: u64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(
+    only(tag, id),
+    compare_order(tag),
+    method = "eq_meta",
+    diff = "diff_fields",
+    try_method = "check_eq"
+)]
+struct Selected {
+    id: u64,
+    name: String,
+    tag: char,
+}
+
+fn forward(id: u64, name: &str, tag: char) -> Forward {
+    Forward {
+        id,
+        name: name.into(),
+        tag,
+        ts: 0,
+    }
+}
+
+fn reversed(id: u64, name: &str, tag: char) -> Reversed {
+    Reversed {
+        ts: 0,
+        tag,
+        name: name.into(),
+        id,
+    }
+}
+
+#[test]
+fn equality_does_not_depend_on_declaration_order() {
+    let cases = [
+        ((1, "a", 'x'), (1, "a", 'x')),
+        ((1, "a", 'x'), (2, "a", 'x')),
+        ((1, "a", 'x'), (1, "b", 'y')),
+        ((1, "a", 'x'), (2, "b", 'y')),
+    ];
+    for ((i1, n1, t1), (i2, n2, t2)) in cases {
+        assert_eq!(
+            forward(i1, n1, t1).eq_meta(&forward(i2, n2, t2)),
+            reversed(i1, n1, t1).eq_meta(&reversed(i2, n2, t2)),
+        );
+    }
+}
+
+#[test]
+fn reports_follow_declaration_order() {
+    let a = forward(1, "a", 'x');
+    let b = Forward {
+        ts: 9,
+        ..forward(2, "b", 'y')
+    };
+    assert_eq!(a.diff_fields(&b), ["id", "name", "tag"]);
+    assert_eq!(a.check_eq(&b), Err("id"));
+    let names = a
+        .field_equality(&b)
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["id", "name", "tag"]);
+    assert_ne!(a.ts, b.ts);
+
+    let c = reversed(1, "a", 'x');
+    let d = reversed(2, "b", 'y');
+    assert_eq!(c.diff_fields(&d), ["tag", "name", "id"]);
+    assert_eq!(c.check_eq(&d), Err("tag"));
+    assert_eq!(c.ts, d.ts);
+}
+
+#[test]
+fn option_order_does_not_reorder_reports() {
+    let a = Selected {
+        id: 1,
+        name: "a".into(),
+        tag: 'x',
+    };
+    let b = Selected {
+        id: 2,
+        name: "b".into(),
+        tag: 'y',
+    };
+    assert!(!a.eq_meta(&b));
+    assert_eq!(a.diff_fields(&b), ["id", "tag"]);
+    assert_eq!(a.check_eq(&b), Err("id"));
+    assert_ne!(a.name, b.name);
+}
@@ -0,0 +1,94 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), first_diff = "first_diff", diff_enum = "ItemFieldDiff")]
+struct Item {
+    id: u64,
+    display_name: String,
+    ts: u64,
+}
+
+#[derive(Debug)]
+#[subset_eq(
+    first_diff = "first_diff",
+    diff_enum = "PairDiff",
+    method_suffix = "_v2"
+)]
+struct Pair(u8, char);
+
+#[derive(Debug, Clone, PartialEq)]
+struct Weight(u8);
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(first_diff = "first_diff", diff_enum = "TreeDiff")]
+struct Tree {
+    weight: Weight,
+    kids: Vec<Self>,
+}
+
+#[test]
+fn reports_the_differing_field_with_both_values() {
+    let a = Item {
+        id: 1,
+        display_name: "a".into(),
+        ts: 0,
+    };
+    let b = Item {
+        display_name: "b".into(),
+        ..a.clone()
+    };
+    assert_eq!(
+        a.first_diff(&b),
+        Some(ItemFieldDiff::DisplayName("a".into(), "b".into()))
+    );
+    assert_eq!(
+        a.first_diff(&Item { id: 2, ..b }),
+        Some(ItemFieldDiff::Id(1, 2))
+    );
+}
+
+#[test]
+fn ignored_fields_never_show_up() {
+    let a = Item {
+        id: 1,
+        display_name: "a".into(),
+        ts: 0,
+    };
+    assert_eq!(a.first_diff(&Item { ts: 9, ..a.clone() }), None);
+}
+
+#[test]
+fn tuple_fields_are_numbered() {
+    assert_eq!(
+        Pair(1, 'x').first_diff_v2(&Pair(1, 'y')),
+        Some(PairDiff::Field1('x', 'y'))
+    );
+    assert_eq!(Pair(1, 'x').first_diff_v2(&Pair(1, 'x')), None);
+}
+
+#[test]
+fn self_typed_fields_hold_the_struct() {
+    let leaf = Tree {
+        weight: Weight(1),
+        kids: Vec::new(),
+    };
+    let a = Tree {
+        weight: Weight(2),
+        kids: vec![leaf.clone()],
+    };
+    let b = Tree {
+        kids: Vec::new(),
+        ..a.clone()
+    };
+    assert_eq!(
+        a.first_diff(&b),
+        Some(TreeDiff::Kids(vec![leaf], Vec::new()))
+    );
+    assert_eq!(
+        a.first_diff(&Tree {
+            weight: Weight(3),
+            ..a.clone()
+        }),
+        Some(TreeDiff::Weight(Weight(2), Weight(3)))
+    );
+}
@@ -0,0 +1,63 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), flexible_rhs, method = "eq_meta", ne_method = "ne_meta")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+#[derive(Debug, Clone)]
+struct ItemDto {
+    id: u64,
+    name: String,
+}
+
+#[subset_eq(ignore(ts), flexible_rhs, other = "ItemDto", method = "matches_dto")]
+struct Record {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+fn item(ts: i64) -> Item {
+    Item {
+        id: 1,
+        name: "a".into(),
+        ts,
+    }
+}
+
+#[test]
+fn accepts_references_owned_values_and_boxes() {
+    let a = item(1);
+    let same = item(2);
+    assert!(a.eq_meta(&same));
+    assert!(a.eq_meta(Box::new(same.clone())));
+    assert!(a.eq_meta(same));
+
+    let mut b = item(2);
+    b.name = "b".into();
+    assert!(!a.eq_meta(&b));
+    assert!(a.ne_meta(&b));
+    assert!(!a.eq_meta(Box::new(b.clone())));
+    assert!(!a.eq_meta(b));
+}
+
+#[test]
+fn applies_to_the_other_type() {
+    let record = Record {
+        id: 1,
+        name: "a".into(),
+        ts: 5,
+    };
+    let dto = ItemDto {
+        id: 1,
+        name: "a".into(),
+    };
+    assert_eq!(record.ts, 5);
+    assert!(record.matches_dto(&dto));
+    assert!(record.matches_dto(Box::new(dto.clone())));
+    assert!(record.matches_dto(dto));
+}
@@ -0,0 +1,45 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), fold_method = "fold_fields")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+    tags: Vec<&'static str>,
+}
+
+#[derive(Debug)]
+#[subset_eq(fold_method = "fold_fields")]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[test]
+fn folds_compared_fields_in_declaration_order() {
+    let item = Item {
+        id: 7,
+        name: "a".into(),
+        ts: 99,
+        tags: vec!["x"],
+    };
+    let joined = item.fold_fields(String::new(), |mut acc, field| {
+        if !acc.is_empty() {
+            acc.push('|');
+        }
+        acc.push_str(&format!("{field:?}"));
+        acc
+    });
+    assert_eq!(joined, r#"7|"a"|["x"]"#);
+    assert_eq!(item.fold_fields(0, |count, _| count + 1), 3);
+    assert_eq!(item.ts, 99);
+}
+
+#[test]
+fn generic_fields_need_debug() {
+    let w = Wrapper { value: 1.5 };
+    assert_eq!(
+        w.fold_fields(String::new(), |acc, f| acc + &format!("{f:?}")),
+        "1.5"
+    );
+}
@@ -0,0 +1,68 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), fn = "eq_meta_fn")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), method = "eq_meta", fn = "tagged_eq_meta")]
+struct Tagged {
+    tag: String,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), fn = "wrapper_eq")]
+struct Wrapper<T> {
+    value: T,
+    ts: i64,
+}
+
+fn item(id: u64, ts: i64) -> Item {
+    Item {
+        id,
+        name: "foo".into(),
+        ts,
+    }
+}
+
+#[test]
+fn free_function_ignores_fields() {
+    assert!(eq_meta_fn(&item(1, 0), &item(1, 5)));
+    assert!(!eq_meta_fn(&item(1, 0), &item(2, 0)));
+}
+
+#[test]
+fn usable_as_comparator() {
+    let cmp: fn(&Item, &Item) -> bool = eq_meta_fn;
+    let mut items = vec![item(1, 0), item(1, 1), item(2, 2), item(2, 3), item(1, 4)];
+    items.dedup_by(|x, y| cmp(x, y));
+    let ids: Vec<_> = items.iter().map(|i| (i.id, i.ts)).collect();
+    assert_eq!(ids, vec![(1, 0), (2, 2), (1, 4)]);
+}
+
+#[test]
+fn method_and_function_together() {
+    let a = Tagged {
+        tag: "x".into(),
+        ts: 0,
+    };
+    let b = Tagged {
+        tag: "x".into(),
+        ts: 1,
+    };
+    assert!(a.eq_meta(&b));
+    assert!(tagged_eq_meta(&a, &b));
+}
+
+#[test]
+fn generic_struct() {
+    let a = Wrapper { value: 1u8, ts: 0 };
+    let b = Wrapper { value: 1u8, ts: 9 };
+    assert!(wrapper_eq(&a, &b));
+    assert!(!wrapper_eq(&a, &Wrapper { value: 2u8, ts: 0 }));
+}
@@ -0,0 +1,47 @@
+use subset_eq::subset_eq;
+
+#[derive(Clone)]
+#[subset_eq(ignore(ts), method = "eq_meta", full_method = "eq_all")]
+struct Event {
+    id: u64,
+    kind: String,
+    ts: i64,
+}
+
+#[subset_eq(ignore(1), full_method = "eq_all")]
+struct Pair(u8, u8);
+
+fn event() -> Event {
+    Event {
+        id: 1,
+        kind: "click".into(),
+        ts: 0,
+    }
+}
+
+#[test]
+fn full_method_sees_ignored_fields() {
+    let a = event();
+    let mut b = a.clone();
+    b.ts = 5;
+    assert!(a.eq_meta(&b));
+    assert!(!a.eq_all(&b));
+    b.ts = a.ts;
+    assert!(a.eq_all(&b));
+}
+
+#[test]
+fn both_methods_see_compared_fields() {
+    let a = event();
+    let mut b = a.clone();
+    b.kind = "hover".into();
+    assert!(!a.eq_meta(&b));
+    assert!(!a.eq_all(&b));
+    assert_eq!(a.id, b.id);
+}
+
+#[test]
+fn full_method_alone_replaces_the_default_method() {
+    assert!(Pair(1, 2).eq_all(&Pair(1, 2)));
+    assert!(!Pair(1, 2).eq_all(&Pair(1, 3)));
+}
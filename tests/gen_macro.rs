@@ -0,0 +1,33 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), method = "eq_meta", gen_macro)]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), gen_macro)]
+struct UserProfile {
+    name: &'static str,
+    ts: i64,
+}
+
+#[test]
+fn macro_calls_the_named_method() {
+    let a = Item { id: 1, ts: 0 };
+    let b = Item { id: 1, ts: 5 };
+    assert!(item_matches!(a, b));
+    assert!(!item_matches!(a, Item { id: 2, ts: 0 }));
+    assert_eq!(a.ts + b.ts, 5);
+}
+
+#[test]
+fn macro_name_is_snake_case_and_uses_the_default_method() {
+    let a = UserProfile { name: "x", ts: 0 };
+    let b = UserProfile { name: "x", ts: 1 };
+    assert!(user_profile_matches!(a, b,));
+    assert!(a.eq_subset_ignoring(&b));
+    assert_eq!(a.ts + b.ts, 1);
+}
@@ -0,0 +1,64 @@
+use proptest::prelude::*;
+use subset_eq::subset_eq;
+
+#[derive(Clone, Copy, Debug)]
+#[subset_eq(ignore(ts), gen_proptest, method = "eq_meta")]
+struct Item {
+    id: u8,
+    flag: bool,
+    ts: u8,
+}
+
+// Few ids and flags, so generated items often match and the laws are exercised, not vacuous.
+impl Arbitrary for Item {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        (0..3u8, any::<bool>(), any::<u8>())
+            .prop_map(|(id, flag, ts)| Item { id, flag, ts })
+            .boxed()
+    }
+}
+
+#[derive(Clone, Copy)]
+#[subset_eq(approx(x = 1.0), gen_proptest, by_value)]
+struct Point {
+    x: f64,
+}
+
+proptest! {
+    #[test]
+    fn the_subset_equality_is_reflexive(a in any::<Item>()) {
+        prop_assert!(a.__subset_reflexive());
+    }
+
+    #[test]
+    fn the_subset_equality_is_symmetric(a in any::<Item>(), b in any::<Item>()) {
+        prop_assert!(Item::__subset_symmetric(&a, &b));
+    }
+
+    #[test]
+    fn the_subset_equality_is_transitive(
+        a in any::<Item>(),
+        b in any::<Item>(),
+        c in any::<Item>(),
+    ) {
+        prop_assert!(Item::__subset_transitive(&a, &b, &c));
+    }
+
+    #[test]
+    fn the_ignored_field_is_not_compared(a in any::<Item>(), ts in any::<u8>()) {
+        prop_assume!(a.ts != ts);
+        let b = Item { ts, ..a };
+        prop_assert!(a.eq_meta(&b));
+    }
+}
+
+#[test]
+fn tolerance_rules_are_not_transitive() {
+    let p = |x| Point { x };
+    assert!(p(0.0).__subset_reflexive());
+    assert!(Point::__subset_symmetric(&p(0.0), &p(0.8)));
+    assert!(!Point::__subset_transitive(&p(0.0), &p(0.8), &p(1.6)));
+}
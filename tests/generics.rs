@@ -0,0 +1,118 @@
+use std::fmt::Debug;
+use subset_eq::subset_eq;
+use subset_eq_traits::SubsetEq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), method = "eq_meta", diff = "diff_fields")]
+struct Wrapper<T> {
+    id: u64,
+    payload: T,
+    ts: i64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), as_trait, method = "eq_meta")]
+struct Borrowed<'a, T: Debug>
+where
+    T: Clone,
+{
+    name: &'a str,
+    values: Vec<T>,
+    ts: i64,
+}
+
+/// Not `PartialEq`: only usable when ignored.
+#[derive(Debug, Clone)]
+struct Opaque;
+
+#[subset_eq(ignore(handle), method = "eq_visible")]
+struct Handle<H> {
+    id: u64,
+    handle: H,
+}
+
+trait Store {
+    type Key;
+}
+
+struct Memory;
+
+impl Store for Memory {
+    type Key = String;
+}
+
+/// The existing where-clause on an associated type must survive the added bounds.
+#[subset_eq(
+    ignore(revision),
+    as_trait,
+    method = "eq_key",
+    where_bound = "S::Key: Clone"
+)]
+struct Entry<S: Store>
+where
+    S::Key: PartialEq,
+{
+    key: S::Key,
+    size: u64,
+    revision: u64,
+}
+
+#[test]
+fn generic_payload_is_compared() {
+    let a = Wrapper {
+        id: 1,
+        payload: "x".to_string(),
+        ts: 0,
+    };
+    let mut b = a.clone();
+    b.ts = 10;
+    assert!(a.eq_meta(&b));
+    b.payload = "y".into();
+    assert!(!a.eq_meta(&b));
+    assert_eq!(a.diff_fields(&b), vec!["payload"]);
+}
+
+#[test]
+fn lifetimes_and_where_clauses_are_preserved() {
+    let name = String::from("n");
+    let a = Borrowed {
+        name: &name,
+        values: vec![1, 2],
+        ts: 0,
+    };
+    let b = Borrowed {
+        name: "n",
+        values: vec![1, 2],
+        ts: 5,
+    };
+    assert_ne!(a.ts, b.ts);
+    assert!(a.eq_meta(&b));
+    assert!(a.subset_eq(&b));
+}
+
+#[test]
+fn ignored_generic_field_needs_no_bound() {
+    let a = Handle {
+        id: 1,
+        handle: Opaque,
+    };
+    let b = Handle {
+        id: 1,
+        handle: Opaque,
+    };
+    assert!(matches!(b.handle, Opaque));
+    assert!(a.eq_visible(&b));
+}
+
+#[test]
+fn associated_type_where_clause_is_merged() {
+    let entry = |size, revision| Entry::<Memory> {
+        key: "k".into(),
+        size,
+        revision,
+    };
+    assert!(entry(1, 0).eq_key(&entry(1, 7)));
+    assert!(!entry(1, 0).eq_key(&entry(2, 0)));
+    assert!(entry(1, 0).subset_eq(&entry(1, 3)));
+    assert_eq!(entry(1, 4).revision, 4);
+}
@@ -0,0 +1,70 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(group(header = (version, id), body = (payload, checksum)))]
+struct Record {
+    id: u32,
+    version: u16,
+    payload: Vec<u8>,
+    checksum: u32,
+    received_at: u64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(
+    group(key = (0), full = (0, 1)),
+    allow_group_overlap,
+    method = "eq_meta",
+    ignore(1)
+)]
+struct Pair(u8, u8);
+
+fn record() -> Record {
+    Record {
+        id: 1,
+        version: 2,
+        payload: vec![1, 2, 3],
+        checksum: 6,
+        received_at: 0,
+    }
+}
+
+#[test]
+fn compares_each_group_on_its_own() {
+    let a = record();
+    let b = Record {
+        payload: vec![9],
+        checksum: 9,
+        ..a.clone()
+    };
+    assert!(a.eq_header(&b));
+    assert!(!a.eq_body(&b));
+
+    let c = Record {
+        version: 3,
+        received_at: 7,
+        ..a.clone()
+    };
+    assert!(!a.eq_header(&c));
+    assert!(a.eq_body(&c));
+}
+
+#[test]
+fn ungrouped_fields_are_left_out() {
+    let a = record();
+    let b = Record {
+        received_at: 99,
+        ..a.clone()
+    };
+    assert!(a.eq_header(&b) && a.eq_body(&b));
+    assert_ne!(a, b);
+}
+
+#[test]
+fn overlapping_groups_and_main_method() {
+    let a = Pair(1, 2);
+    let b = Pair(1, 3);
+    assert!(a.eq_key(&b));
+    assert!(!a.eq_full(&b));
+    assert!(a.eq_meta(&b));
+}
@@ -0,0 +1,60 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), method = "eq_meta", hash_method = "hash_subset")]
+struct Record {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+/// Map key using the subset semantics.
+struct Key(Record);
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_subset(state);
+    }
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_meta(&other.0)
+    }
+}
+
+impl Eq for Key {}
+
+fn record(id: u64, ts: i64) -> Record {
+    Record {
+        id,
+        name: "foo".into(),
+        ts,
+    }
+}
+
+fn hash_of(record: &Record) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    record.hash_subset(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn ignored_fields_do_not_affect_hash() {
+    assert_eq!(hash_of(&record(1, 0)), hash_of(&record(1, 99)));
+    assert_ne!(hash_of(&record(1, 0)), hash_of(&record(2, 0)));
+}
+
+#[test]
+fn records_differing_in_ignored_fields_collide() {
+    let mut counts = HashMap::new();
+    for r in [record(1, 0), record(1, 5), record(2, 0)] {
+        *counts.entry(Key(r)).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&Key(record(1, 42))], 2);
+    assert_eq!(counts[&Key(record(2, 42))], 1);
+}
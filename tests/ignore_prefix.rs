@@ -0,0 +1,67 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore_prefix("cache_", "tmp_"), method = "eq_stable")]
+struct Record {
+    id: u64,
+    cache_token: String,
+    cache_hits: u32,
+    tmp_path: String,
+    name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(
+    ignore(updated_at),
+    ignore_prefix("cache_", "unused_"),
+    method = "eq_core"
+)]
+struct Combined {
+    id: u64,
+    cache_token: String,
+    updated_at: i64,
+}
+
+fn record() -> Record {
+    Record {
+        id: 1,
+        cache_token: "a".into(),
+        cache_hits: 3,
+        tmp_path: "/tmp/x".into(),
+        name: "n".into(),
+    }
+}
+
+#[test]
+fn prefixed_fields_are_ignored() {
+    let a = record();
+    let mut b = a.clone();
+    b.cache_token = "b".into();
+    b.cache_hits = 10;
+    b.tmp_path = "/tmp/y".into();
+    assert_ne!(a, b);
+    assert!(a.eq_stable(&b));
+}
+
+#[test]
+fn unprefixed_fields_are_compared() {
+    let a = record();
+    let mut b = a.clone();
+    b.name = "m".into();
+    assert!(!a.eq_stable(&b));
+}
+
+#[test]
+fn prefixes_combine_with_explicit_ignores() {
+    let a = Combined {
+        id: 1,
+        cache_token: "a".into(),
+        updated_at: 0,
+    };
+    let b = Combined {
+        id: 1,
+        cache_token: "b".into(),
+        updated_at: 5,
+    };
+    assert!(a.eq_core(&b));
+}
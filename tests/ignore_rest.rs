@@ -0,0 +1,47 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(.., keep = (id, name)), method = "eq_identity")]
+struct Item {
+    id: u64,
+    name: String,
+    updated_at: i64,
+    cache_token: u32,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(.., keep = (1)), diff = "diff_fields")]
+struct Pair(i64, i64, String);
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "a".into(),
+        updated_at: 0,
+        cache_token: 7,
+    }
+}
+
+#[test]
+fn compares_only_the_kept_fields() {
+    let a = item();
+    let b = Item {
+        updated_at: 5,
+        cache_token: 9,
+        ..item()
+    };
+    assert!(a.eq_identity(&b));
+    assert!(!a.eq_identity(&Item {
+        name: "b".into(),
+        ..item()
+    }));
+    assert_ne!(a.updated_at + i64::from(a.cache_token), 0);
+}
+
+#[test]
+fn a_single_kept_field_needs_no_tuple() {
+    let a = Pair(1, 2, "x".into());
+    let b = Pair(3, 4, "y".into());
+    assert_eq!(a.diff_fields(&b), ["1"]);
+    assert_eq!((a.0, a.2.as_str()), (1, "x"));
+}
@@ -0,0 +1,49 @@
+use std::time::{Duration, Instant};
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore_type("std::time::Instant", "u128"), method = "eq_stable")]
+struct Job {
+    name: String,
+    started: Instant,
+    finished: std::time::Instant,
+    elapsed_nanos: u128,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore_type("Option<u8>"), method = "eq_required")]
+struct Form {
+    name: String,
+    age: Option<u8>,
+    rank: Option<u16>,
+}
+
+fn job(name: &str, offset: u64) -> Job {
+    let now = Instant::now() + Duration::from_secs(offset);
+    Job {
+        name: name.into(),
+        started: now,
+        finished: now,
+        elapsed_nanos: u128::from(offset),
+    }
+}
+
+#[test]
+fn ignores_every_field_of_listed_types() {
+    assert!(job("build", 0).eq_stable(&job("build", 10)));
+    assert!(!job("build", 0).eq_stable(&job("test", 0)));
+}
+
+#[test]
+fn generic_arguments_must_match() {
+    let a = Form {
+        name: "x".into(),
+        age: Some(1),
+        rank: Some(1),
+    };
+    let mut b = a.clone();
+    b.age = None;
+    assert!(a.eq_required(&b));
+    b.rank = None;
+    assert!(!a.eq_required(&b));
+}
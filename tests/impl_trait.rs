@@ -0,0 +1,46 @@
+use subset_eq::subset_eq;
+
+trait MyCompare {
+    fn compare(&self, other: &Self) -> bool;
+}
+
+#[subset_eq(
+    ignore(ts),
+    impl_trait = "MyCompare",
+    method = "compare",
+    ne_method = "differs"
+)]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+#[subset_eq(impl_trait = "MyCompare", method = "compare", ignore(cache))]
+struct Wrapper<T> {
+    value: T,
+    cache: Vec<u8>,
+}
+
+fn all_match<T: MyCompare>(items: &[T], reference: &T) -> bool {
+    items.iter().all(|item| item.compare(reference))
+}
+
+#[test]
+fn implements_the_user_trait() {
+    let reference = Item { id: 1, ts: 0 };
+    assert!(all_match(
+        &[Item { id: 1, ts: 5 }, Item { id: 1, ts: 6 }],
+        &reference
+    ));
+    assert!(!all_match(&[Item { id: 2, ts: 0 }], &reference));
+    assert!(Item { id: 3, ts: 0 }.differs(&reference));
+    assert_eq!(reference.ts, 0);
+}
+
+#[test]
+fn carries_bounds_on_the_trait_impl() {
+    let w = |value, cache| Wrapper { value, cache };
+    assert!(w("a", vec![1]).compare(&w("a", vec![2])));
+    assert!(!w("a", vec![]).compare(&w("b", vec![])));
+    assert!(w("c", vec![]).cache.is_empty());
+}
@@ -0,0 +1,70 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(updated_at, cache_token), invert, method = "eq_meta_only")]
+struct Doc {
+    id: u64,
+    body: String,
+    updated_at: i64,
+    cache_token: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(updated_at, cache_token), method = "eq_content")]
+struct Page {
+    id: u64,
+    body: String,
+    updated_at: i64,
+    cache_token: u32,
+}
+
+fn doc() -> Doc {
+    Doc {
+        id: 1,
+        body: "hello".into(),
+        updated_at: 10,
+        cache_token: 7,
+    }
+}
+
+#[test]
+fn compares_only_the_ignored_fields() {
+    let a = doc();
+    let mut b = a.clone();
+    b.id = 2;
+    b.body = "changed".into();
+    assert!(a.eq_meta_only(&b));
+
+    b.updated_at = 11;
+    assert!(!a.eq_meta_only(&b));
+
+    let mut c = a.clone();
+    c.cache_token = 8;
+    assert!(!a.eq_meta_only(&c));
+}
+
+#[test]
+fn complements_the_normal_comparison() {
+    let a = Page {
+        id: 1,
+        body: "hello".into(),
+        updated_at: 10,
+        cache_token: 7,
+    };
+    let mut content_changed = a.clone();
+    content_changed.body = "changed".into();
+    assert!(!a.eq_content(&content_changed));
+
+    let mut meta_changed = a.clone();
+    meta_changed.updated_at = 11;
+    assert!(a.eq_content(&meta_changed));
+
+    let as_doc = |p: &Page| Doc {
+        id: p.id,
+        body: p.body.clone(),
+        updated_at: p.updated_at,
+        cache_token: p.cache_token,
+    };
+    assert!(as_doc(&a).eq_meta_only(&as_doc(&content_changed)));
+    assert!(!as_doc(&a).eq_meta_only(&as_doc(&meta_changed)));
+}
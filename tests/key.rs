@@ -0,0 +1,84 @@
+use subset_eq::subset_eq;
+
+trait Component {
+    fn id(&self) -> u32;
+}
+
+struct Button {
+    id: u32,
+}
+
+struct Slider {
+    id: u32,
+}
+
+impl Component for Button {
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl Component for Slider {
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+#[subset_eq(key(component = |c| c.id()), ignore(ts), method = "eq_by_key")]
+struct Widget {
+    name: String,
+    component: Box<dyn Component>,
+    ts: i64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(key(0 = |s: &String| s.to_lowercase()), method = "eq_folded")]
+struct Label(String);
+
+#[subset_eq(key(component = "|c| c.id()"), method = "eq_by_quoted_key")]
+struct QuotedWidget {
+    component: Box<dyn Component>,
+}
+
+#[test]
+fn compares_trait_objects_by_key() {
+    let a = Widget {
+        name: "w".into(),
+        component: Box::new(Button { id: 7 }),
+        ts: 0,
+    };
+    let b = Widget {
+        name: "w".into(),
+        component: Box::new(Slider { id: 7 }),
+        ts: 1,
+    };
+    let c = Widget {
+        name: "w".into(),
+        component: Box::new(Slider { id: 8 }),
+        ts: 0,
+    };
+    assert!(a.eq_by_key(&b));
+    assert!(!a.eq_by_key(&c));
+    assert_ne!(a.ts, b.ts);
+}
+
+#[test]
+fn closures_may_annotate_their_argument() {
+    assert!(Label("Hello".into()).eq_folded(&Label("HELLO".into())));
+    assert!(!Label("Hello".into()).eq_folded(&Label("World".into())));
+}
+
+#[test]
+fn quoted_closures_are_parsed() {
+    let a = QuotedWidget {
+        component: Box::new(Button { id: 7 }),
+    };
+    let b = QuotedWidget {
+        component: Box::new(Slider { id: 7 }),
+    };
+    let c = QuotedWidget {
+        component: Box::new(Slider { id: 8 }),
+    };
+    assert!(a.eq_by_quoted_key(&b));
+    assert!(!a.eq_by_quoted_key(&c));
+}
@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), key_wrapper = "ItemKey")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+#[subset_eq(ignore(1), key_wrapper = "PairKey")]
+struct Pair<T>(T, u32);
+
+#[test]
+fn items_differing_in_ignored_fields_share_a_key() {
+    let mut map = HashMap::new();
+    let item = |ts| Item {
+        id: 1,
+        name: "a".into(),
+        ts,
+    };
+    map.insert(ItemKey(item(10)), "first");
+    map.insert(ItemKey(item(20)), "second");
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&ItemKey(item(30))], "second");
+    // The first key is kept, along with the item it owns.
+    assert_eq!(map.keys().next().map(|key| key.0.ts), Some(10));
+}
+
+#[test]
+fn compared_fields_still_tell_keys_apart() {
+    let mut set = HashSet::new();
+    set.insert(ItemKey(Item {
+        id: 1,
+        name: "a".into(),
+        ts: 0,
+    }));
+    set.insert(ItemKey(Item {
+        id: 2,
+        name: "a".into(),
+        ts: 0,
+    }));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn wraps_generic_tuple_structs() {
+    let set = [
+        PairKey(Pair("x", 1)),
+        PairKey(Pair("x", 2)),
+        PairKey(Pair("y", 1)),
+    ]
+    .into_iter()
+    .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+    assert!(set.iter().all(|key| key.0 .1 > 0));
+}
+
+mod private {
+    use std::collections::HashSet;
+
+    use subset_eq::subset_eq;
+
+    #[subset_eq(ignore(ts), key_wrapper = "EventKey")]
+    struct Event {
+        id: u64,
+        ts: i64,
+    }
+
+    /// Keeps the first timestamp seen for every id.
+    pub(super) fn first_seen(events: &[(u64, i64)]) -> Vec<i64> {
+        let mut seen = HashSet::new();
+        let mut firsts: Vec<i64> = events
+            .iter()
+            .map(|&(id, ts)| EventKey(Event { id, ts }))
+            .filter_map(|key| {
+                let ts = key.0.ts;
+                seen.insert(key).then_some(ts)
+            })
+            .collect();
+        firsts.sort();
+        firsts
+    }
+}
+
+#[test]
+fn wraps_private_structs() {
+    assert_eq!(private::first_seen(&[(1, 3), (1, 5), (2, 0)]), [0, 3]);
+}
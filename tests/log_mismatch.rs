@@ -0,0 +1,102 @@
+use std::{cell::RefCell, sync::Once};
+
+use log::{LevelFilter, Log, Metadata, Record};
+use subset_eq::subset_eq;
+
+thread_local! {
+    static LOGGED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records messages on the logging thread, so tests running in parallel only see their own.
+struct Capture;
+
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        LOGGED.with(|logged| logged.borrow_mut().push(record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+static CAPTURE: Capture = Capture;
+
+/// Installs the capturing logger on first use and returns what this thread logged since last time.
+fn take_logged() -> Vec<String> {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        log::set_logger(&CAPTURE).unwrap();
+        log::set_max_level(LevelFilter::Debug);
+    });
+    LOGGED.with(|logged| logged.take())
+}
+
+/// What a mismatch logs: the message in debug builds, nothing in release builds.
+fn expected(message: &str) -> Vec<String> {
+    if cfg!(debug_assertions) {
+        vec![message.to_string()]
+    } else {
+        Vec::new()
+    }
+}
+
+#[derive(Clone)]
+#[subset_eq(ignore(ts), log_mismatch, method = "eq_meta", ne_method = "ne_meta")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+#[subset_eq(log_mismatch)]
+struct Wrapper<T> {
+    value: T,
+}
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "a".into(),
+        ts: 0,
+    }
+}
+
+#[test]
+fn logs_the_first_differing_field() {
+    take_logged();
+    let a = item();
+    let mut b = a.clone();
+    b.name = "b".into();
+    b.id = 2;
+    assert!(!a.eq_meta(&b));
+    assert_eq!(take_logged(), expected("Item: `id` differs: 1 != 2"));
+    b.id = 1;
+    assert!(a.ne_meta(&b));
+    assert_eq!(
+        take_logged(),
+        expected(r#"Item: `name` differs: "a" != "b""#)
+    );
+}
+
+#[test]
+fn logs_nothing_when_equal() {
+    take_logged();
+    let a = item();
+    let mut b = a.clone();
+    b.ts = 9;
+    assert!(a.eq_meta(&b));
+    assert!(take_logged().is_empty());
+}
+
+#[test]
+fn formats_generic_fields() {
+    take_logged();
+    assert!(!Wrapper { value: 'x' }.eq_subset_ignoring(&Wrapper { value: 'y' }));
+    assert_eq!(
+        take_logged(),
+        expected("Wrapper: `value` differs: 'x' != 'y'")
+    );
+}
@@ -0,0 +1,58 @@
+use std::collections::{BTreeMap, HashMap};
+
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(map_ignore_keys(settings = ["last_seen", "nonce"]), method = "eq_config")]
+struct Config {
+    name: String,
+    settings: HashMap<String, i32>,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(map_ignore_keys(0 = [0, 9]))]
+struct Slots(BTreeMap<u8, &'static str>);
+
+fn config(nonce: i32) -> Config {
+    Config {
+        name: "svc".into(),
+        settings: HashMap::from([
+            ("retries".into(), 3),
+            ("nonce".into(), nonce),
+            ("last_seen".into(), nonce * 10),
+        ]),
+    }
+}
+
+#[test]
+fn ignored_keys_do_not_count() {
+    let a = config(1);
+    let b = config(2);
+    assert_ne!(a, b);
+    assert!(a.eq_config(&b));
+}
+
+#[test]
+fn other_keys_still_count() {
+    let a = config(1);
+    let mut b = a.clone();
+    b.settings.insert("retries".into(), 4);
+    assert!(!a.eq_config(&b));
+
+    let mut c = a.clone();
+    c.settings.insert("timeout".into(), 30);
+    assert!(!a.eq_config(&c));
+
+    // A missing ignored key is the same as a different value.
+    let mut d = a.clone();
+    d.settings.remove("nonce");
+    assert!(a.eq_config(&d));
+}
+
+#[test]
+fn non_string_keys_in_a_btree_map() {
+    let a = Slots(BTreeMap::from([(0, "boot"), (1, "app"), (9, "tmp")]));
+    let b = Slots(BTreeMap::from([(1, "app")]));
+    assert!(a.eq_subset_ignoring(&b));
+    assert!(!a.eq_subset_ignoring(&Slots(BTreeMap::from([(1, "other")]))));
+}
@@ -0,0 +1,90 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(
+    ignore(ts),
+    method = "eq_meta",
+    method_suffix = "_v1",
+    diff = "diff_fields"
+)]
+#[subset_eq(
+    ignore(ts, name),
+    method = "eq_meta",
+    method_suffix = "_v2",
+    diff = "diff_fields"
+)]
+struct Item {
+    id: u64,
+    name: String,
+    #[subset_skip]
+    cache: u32,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), method_suffix = "_a")]
+#[subset_eq(ignore(ts, tag), method_suffix = "_b")]
+struct Defaults {
+    id: u64,
+    tag: u8,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(method = "eq_meta", ne_method = "ne_meta", method_suffix = "_strict")]
+struct Named {
+    id: u64,
+}
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "foo".into(),
+        cache: 0,
+        ts: 0,
+    }
+}
+
+#[test]
+fn stacked_configs_coexist() {
+    let a = item();
+    let mut b = a.clone();
+    b.name = "bar".into();
+    b.ts = 9;
+    assert!(!a.eq_meta_v1(&b));
+    assert!(a.eq_meta_v2(&b));
+    assert_eq!(a.diff_fields_v1(&b), vec!["name"]);
+    assert!(a.diff_fields_v2(&b).is_empty());
+}
+
+#[test]
+fn skip_markers_reach_every_stacked_config() {
+    let a = item();
+    let mut b = a.clone();
+    b.cache = 7;
+    assert!(a.eq_meta_v1(&b));
+    assert!(a.eq_meta_v2(&b));
+}
+
+#[test]
+fn suffix_applies_to_explicit_names() {
+    let a = Named { id: 1 };
+    assert!(a.eq_meta_strict(&Named { id: 1 }));
+    assert!(a.ne_meta_strict(&Named { id: 2 }));
+}
+
+#[test]
+fn suffix_applies_to_default_names() {
+    let a = Defaults {
+        id: 1,
+        tag: 1,
+        ts: 0,
+    };
+    let b = Defaults {
+        id: 1,
+        tag: 2,
+        ts: 5,
+    };
+    assert!(!a.eq_subset_ignoring_a(&b));
+    assert!(a.eq_subset_ignoring_b(&b));
+}
@@ -0,0 +1,59 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), module = "item_cmp", method = "eq_meta")]
+struct Item {
+    id: u32,
+    name: String,
+    ts: u64,
+}
+
+fn same_parity(a: &u32, b: &u32) -> bool {
+    a % 2 == b % 2
+}
+
+#[derive(Debug)]
+#[subset_eq(with(level = "same_parity"), module = "tag_cmp", vis = "pub(crate)")]
+struct Tag {
+    level: u32,
+}
+
+#[test]
+fn compares_through_module_function() {
+    let a = Item {
+        id: 1,
+        name: "a".into(),
+        ts: 10,
+    };
+    let b = Item {
+        ts: 20,
+        ..a.clone()
+    };
+    assert_ne!(a, b);
+    assert!(item_cmp::eq_meta(&a, &b));
+    assert!(!item_cmp::eq_meta(&a, &Item { id: 2, ..b }));
+}
+
+#[test]
+fn resolves_comparators_from_the_parent_module() {
+    let a = Tag { level: 1 };
+    assert!(tag_cmp::eq_subset_ignoring(&a, &Tag { level: 3 }));
+    assert!(!tag_cmp::eq_subset_ignoring(&a, &Tag { level: 2 }));
+}
+
+mod inner {
+    use subset_eq::subset_eq;
+
+    #[subset_eq(ignore(ts), module = "cmp", method = "eq_meta")]
+    pub struct Reading {
+        pub value: i32,
+        pub ts: u64,
+    }
+}
+
+#[test]
+fn module_is_reachable_next_to_the_struct() {
+    let a = inner::Reading { value: 3, ts: 1 };
+    assert_eq!(a.ts, 1);
+    assert!(inner::cmp::eq_meta(&a, &inner::Reading { value: 3, ts: 2 }));
+}
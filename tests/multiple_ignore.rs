@@ -0,0 +1,39 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(updated_at), ignore(cache_token, etag), method = "eq_content")]
+struct Doc {
+    id: u64,
+    body: String,
+    updated_at: i64,
+    cache_token: u32,
+    etag: String,
+}
+
+fn doc() -> Doc {
+    Doc {
+        id: 1,
+        body: "hello".into(),
+        updated_at: 0,
+        cache_token: 0,
+        etag: "a".into(),
+    }
+}
+
+#[test]
+fn every_group_is_ignored() {
+    let a = doc();
+    let mut b = a.clone();
+    b.updated_at = 5;
+    b.cache_token = 3;
+    b.etag = "b".into();
+    assert!(a.eq_content(&b));
+}
+
+#[test]
+fn remaining_fields_are_compared() {
+    let a = doc();
+    let mut b = a.clone();
+    b.body = "changed".into();
+    assert!(!a.eq_content(&b));
+}
@@ -0,0 +1,34 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(ts), method = "eq_meta", ne_method = "ne_meta")]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(ts), ne_method = "ne_meta")]
+struct Defaulted {
+    id: u64,
+    ts: i64,
+}
+
+#[test]
+fn ne_method_negates_eq_method() {
+    let a = Item { id: 1, ts: 0 };
+    let b = Item { id: 1, ts: 9 };
+    let c = Item { id: 2, ts: 0 };
+    assert!(a.eq_meta(&b));
+    assert!(!a.ne_meta(&b));
+    assert!(!a.eq_meta(&c));
+    assert!(a.ne_meta(&c));
+}
+
+#[test]
+fn ne_method_without_method_uses_default_name() {
+    let a = Defaulted { id: 1, ts: 0 };
+    let b = Defaulted { id: 1, ts: 3 };
+    assert!(a.eq_subset_ignoring(&b));
+    assert!(!a.ne_meta(&b));
+}
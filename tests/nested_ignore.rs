@@ -0,0 +1,69 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(updated_at))]
+struct Metadata {
+    version: u32,
+    updated_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(meta.updated_at), method = "eq_content")]
+struct Document {
+    title: String,
+    meta: Metadata,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(doc.meta.updated_at, audit))]
+struct Envelope {
+    doc: Document,
+    audit: String,
+}
+
+impl Document {
+    fn eq_subset_ignoring(&self, other: &Self) -> bool {
+        self.eq_content(other)
+    }
+}
+
+fn document() -> Document {
+    Document {
+        title: "t".into(),
+        meta: Metadata {
+            version: 1,
+            updated_at: 0,
+        },
+    }
+}
+
+#[test]
+fn nested_ignored_field_is_skipped() {
+    let a = document();
+    let mut b = a.clone();
+    b.meta.updated_at = 50;
+    assert_ne!(a, b);
+    assert!(a.eq_content(&b));
+}
+
+#[test]
+fn other_nested_fields_are_still_compared() {
+    let a = document();
+    let mut b = a.clone();
+    b.meta.version = 2;
+    assert!(!a.eq_content(&b));
+}
+
+#[test]
+fn deeper_paths_delegate_through_each_level() {
+    let a = Envelope {
+        doc: document(),
+        audit: "x".into(),
+    };
+    let mut b = a.clone();
+    b.doc.meta.updated_at = 9;
+    b.audit = "y".into();
+    assert!(a.eq_subset_ignoring(&b));
+    b.doc.title = "other".into();
+    assert!(!a.eq_subset_ignoring(&b));
+}
@@ -0,0 +1,43 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(method = "eq_full")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq]
+struct Bare(u8, u8);
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "foo".into(),
+        ts: 0,
+    }
+}
+
+#[test]
+fn compares_every_field() {
+    let a = item();
+    assert!(a.eq_full(&a.clone()));
+    for change in [
+        |i: &mut Item| i.id = 2,
+        |i: &mut Item| i.name = "bar".into(),
+        |i: &mut Item| i.ts = 5,
+    ] {
+        let mut b = a.clone();
+        change(&mut b);
+        assert!(!a.eq_full(&b));
+        assert_eq!(a.eq_full(&b), a == b);
+    }
+}
+
+#[test]
+fn bare_attribute_uses_default_name() {
+    assert!(Bare(1, 2).eq_subset_ignoring(&Bare(1, 2)));
+    assert!(!Bare(1, 2).eq_subset_ignoring(&Bare(1, 3)));
+}
@@ -0,0 +1,50 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(
+    ignore(ts),
+    no_std,
+    method = "eq_meta",
+    diff = "diff_fields",
+    field_eq_iter = "field_equality"
+)]
+struct Reading {
+    id: u32,
+    value: i16,
+    #[cfg(any())]
+    extra: u8,
+    unit: char,
+    ts: u64,
+}
+
+fn reading() -> Reading {
+    Reading {
+        id: 1,
+        value: 20,
+        unit: 'C',
+        ts: 0,
+    }
+}
+
+#[test]
+fn diff_returns_an_array_and_a_count() {
+    let a = reading();
+    let b = Reading {
+        value: 21,
+        unit: 'F',
+        ts: 5,
+        ..a.clone()
+    };
+    assert!(!a.eq_meta(&b));
+    let (names, count) = a.diff_fields(&b);
+    assert_eq!(&names[..count], ["value", "unit"]);
+    assert_eq!(a.diff_fields(&Reading { ts: 9, ..a.clone() }).1, 0);
+}
+
+#[test]
+fn field_iterator_skips_gated_fields_without_allocating() {
+    let a = reading();
+    let b = Reading { id: 2, ..a.clone() };
+    let entries = a.field_equality(&b).collect::<Vec<_>>();
+    assert_eq!(entries, [("id", false), ("value", true), ("unit", true)]);
+}
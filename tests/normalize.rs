@@ -0,0 +1,51 @@
+use subset_eq::subset_eq;
+
+fn lowercase(s: &str) -> String {
+    s.to_lowercase()
+}
+
+#[derive(Debug, PartialEq)]
+#[subset_eq(
+    normalize(name = "str::trim", email = "lowercase"),
+    method = "eq_norm",
+    diff = "diff_norm"
+)]
+struct Contact {
+    name: String,
+    email: String,
+    age: u8,
+}
+
+#[test]
+fn trims_before_comparing() {
+    let a = Contact {
+        name: " foo ".into(),
+        email: "Foo@Example.com".into(),
+        age: 30,
+    };
+    let b = Contact {
+        name: "foo".into(),
+        email: "foo@example.com".into(),
+        age: 30,
+    };
+    assert_ne!(a, b);
+    assert!(a.eq_norm(&b));
+    assert!(a.diff_norm(&b).is_empty());
+}
+
+#[test]
+fn normalized_values_must_still_match() {
+    let a = Contact {
+        name: "foo".into(),
+        email: "foo@example.com".into(),
+        age: 30,
+    };
+    let b = Contact {
+        name: "f oo".into(),
+        email: "foo@example.com".into(),
+        age: 30,
+    };
+    assert!(!a.eq_norm(&b));
+    assert_eq!(a.diff_norm(&b), ["name"]);
+    assert!(!a.eq_norm(&Contact { age: 31, ..b }));
+}
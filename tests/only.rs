@@ -0,0 +1,40 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(only(id, name), method = "eq_identity")]
+struct Record {
+    id: u64,
+    name: String,
+    updated_at: i64,
+    cache_token: String,
+    notes: Vec<String>,
+}
+
+fn record() -> Record {
+    Record {
+        id: 1,
+        name: "foo".into(),
+        updated_at: 10,
+        cache_token: "tok".into(),
+        notes: vec!["a".into()],
+    }
+}
+
+#[test]
+fn only_ignores_unlisted_fields() {
+    let a = record();
+    let mut b = a.clone();
+    b.updated_at = 99;
+    b.cache_token = "other".into();
+    b.notes.clear();
+    assert_ne!(a, b);
+    assert!(a.eq_identity(&b));
+}
+
+#[test]
+fn only_compares_listed_fields() {
+    let a = record();
+    let mut b = a.clone();
+    b.name = "bar".into();
+    assert!(!a.eq_identity(&b));
+}
@@ -0,0 +1,68 @@
+use std::cmp::Ordering;
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), ord_method = "cmp_stable")]
+struct Record {
+    priority: u8,
+    name: String,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), ord_method = "cmp_value")]
+struct Wrapper<T> {
+    value: T,
+    ts: i64,
+}
+
+fn record(priority: u8, name: &str, ts: i64) -> Record {
+    Record {
+        priority,
+        name: name.into(),
+        ts,
+    }
+}
+
+#[test]
+fn ignores_excluded_fields() {
+    assert_eq!(
+        record(1, "a", 100).cmp_stable(&record(1, "a", 0)),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn declaration_order_breaks_ties() {
+    assert_eq!(
+        record(1, "z", 0).cmp_stable(&record(2, "a", 0)),
+        Ordering::Less
+    );
+    assert_eq!(
+        record(2, "a", 0).cmp_stable(&record(2, "b", 0)),
+        Ordering::Less
+    );
+    assert_eq!(
+        record(2, "b", 0).cmp_stable(&record(2, "a", 9)),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn sorts_ignoring_volatile_fields() {
+    let mut records = [record(2, "a", 0), record(1, "b", 5), record(1, "a", 9)];
+    records.sort_by(Record::cmp_stable);
+    let keys: Vec<_> = records
+        .iter()
+        .map(|r| (r.priority, r.name.as_str()))
+        .collect();
+    assert_eq!(keys, vec![(1, "a"), (1, "b"), (2, "a")]);
+}
+
+#[test]
+fn generic_fields_need_ord() {
+    let a = Wrapper { value: 1u8, ts: 5 };
+    let b = Wrapper { value: 2u8, ts: 0 };
+    assert_eq!(a.cmp_value(&b), Ordering::Less);
+    assert_eq!(a.ts, 5);
+}
@@ -0,0 +1,60 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(
+    ignore(updated_at, cache_token),
+    other = "ItemDto",
+    method = "matches_dto"
+)]
+struct Item {
+    id: u64,
+    name: String,
+    updated_at: i64,
+    cache_token: String,
+}
+
+struct ItemDto {
+    id: u64,
+    name: &'static str,
+}
+
+mod wire {
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+}
+
+#[subset_eq(other = wire::Point, method = "matches_wire")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "foo".into(),
+        updated_at: 5,
+        cache_token: "tok".into(),
+    }
+}
+
+#[test]
+fn matches_other_type_on_shared_fields() {
+    let dto = ItemDto { id: 1, name: "foo" };
+    assert!(item().matches_dto(&dto));
+}
+
+#[test]
+fn detects_difference_in_other_type() {
+    let dto = ItemDto { id: 1, name: "bar" };
+    assert!(!item().matches_dto(&dto));
+}
+
+#[test]
+fn other_type_accepts_a_path() {
+    let p = Point { x: 1, y: 2 };
+    assert!(p.matches_wire(&wire::Point { x: 1, y: 2 }));
+    assert!(!p.matches_wire(&wire::Point { x: 2, y: 2 }));
+}
@@ -0,0 +1,70 @@
+use subset_eq::subset_eq;
+
+#[derive(Clone)]
+#[subset_eq(
+    preset = "created_at, updated_at, version",
+    ignore(ts),
+    method = "eq_core"
+)]
+struct Record {
+    id: u64,
+    body: String,
+    created_at: i64,
+    updated_at: i64,
+    version: u32,
+    ts: u64,
+}
+
+fn record() -> Record {
+    Record {
+        id: 1,
+        body: "hello".into(),
+        created_at: 0,
+        updated_at: 0,
+        version: 1,
+        ts: 0,
+    }
+}
+
+#[test]
+fn preset_fields_are_ignored() {
+    let a = record();
+    let mut b = a.clone();
+    b.created_at = 10;
+    b.updated_at = 20;
+    b.version = 2;
+    assert!(a.eq_core(&b));
+    assert_ne!(a.updated_at, b.updated_at);
+}
+
+#[test]
+fn composes_with_explicit_ignores() {
+    let a = record();
+    let mut b = a.clone();
+    b.ts = 99;
+    b.version = 3;
+    assert!(a.eq_core(&b));
+    b.body = "world".into();
+    assert!(!a.eq_core(&b));
+    assert_eq!(a.id, b.id);
+}
+
+#[subset_eq(preset = "created_at, updated_at, version", method = "eq_core")]
+struct Note {
+    text: String,
+    updated_at: i64,
+}
+
+#[test]
+fn preset_fits_structs_missing_some_fields() {
+    let a = Note {
+        text: "n".into(),
+        updated_at: 1,
+    };
+    let b = Note {
+        text: "n".into(),
+        updated_at: 2,
+    };
+    assert!(a.eq_core(&b));
+    assert_ne!(a.updated_at, b.updated_at);
+}
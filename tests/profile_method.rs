@@ -0,0 +1,76 @@
+use subset_eq::subset_eq;
+use subset_eq_traits::{ComparisonProfile, Fuzzy, Strict};
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), profile_method = "eq_with", method = "eq_meta")]
+struct Sample {
+    label: String,
+    value: f64,
+    gain: f32,
+    ts: u64,
+}
+
+/// Treats every float pair as equal, leaving only the other fields.
+struct AnyFloat;
+
+impl ComparisonProfile for AnyFloat {
+    fn f64_eq(_: f64, _: f64) -> bool {
+        true
+    }
+
+    fn f32_eq(_: f32, _: f32) -> bool {
+        true
+    }
+}
+
+fn sample() -> Sample {
+    Sample {
+        label: "a".into(),
+        value: 0.1 + 0.2,
+        gain: 1.0,
+        ts: 0,
+    }
+}
+
+#[test]
+fn strict_and_fuzzy_profiles_disagree_on_rounding() {
+    let a = sample();
+    let b = Sample {
+        value: 0.3,
+        ts: 7,
+        ..sample()
+    };
+    assert!(!a.eq_with::<Strict>(&b));
+    assert!(a.eq_with::<Fuzzy>(&b));
+    assert_eq!(a.eq_with::<Strict>(&b), a.eq_meta(&b));
+}
+
+#[test]
+fn profile_covers_f32_fields_too() {
+    let a = sample();
+    let b = Sample {
+        gain: 1.0 + 1e-7,
+        ..sample()
+    };
+    assert!(!a.eq_with::<Strict>(&b));
+    assert!(a.eq_with::<Fuzzy>(&b));
+    assert!(!a.eq_with::<Fuzzy>(&Sample {
+        gain: 1.1,
+        ..sample()
+    }));
+}
+
+#[test]
+fn non_float_fields_ignore_the_profile() {
+    let a = sample();
+    let b = Sample {
+        label: "b".into(),
+        value: 9.0,
+        ..sample()
+    };
+    assert!(!a.eq_with::<AnyFloat>(&b));
+    assert!(a.eq_with::<AnyFloat>(&Sample {
+        value: 9.0,
+        ..sample()
+    }));
+}
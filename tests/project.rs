@@ -0,0 +1,61 @@
+use subset_eq::subset_eq;
+
+/// Milliseconds since the epoch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Timestamp(u64);
+
+impl Timestamp {
+    fn epoch_seconds(&self) -> u64 {
+        self.0 / 1000
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[subset_eq(project(ts = "epoch_seconds"), method = "eq_sec")]
+struct Event {
+    name: String,
+    ts: Timestamp,
+}
+
+#[derive(Debug)]
+#[subset_eq(project(1 = "len"), ignore(0))]
+struct Labelled(u32, String);
+
+#[test]
+fn compares_field_through_projection() {
+    let a = Event {
+        name: "tick".into(),
+        ts: Timestamp(12_000),
+    };
+    let b = Event {
+        name: "tick".into(),
+        ts: Timestamp(12_999),
+    };
+    assert_ne!(a, b);
+    assert!(a.eq_sec(&b));
+    assert!(!a.eq_sec(&Event {
+        ts: Timestamp(13_000),
+        ..b
+    }));
+}
+
+#[test]
+fn other_fields_still_count() {
+    let a = Event {
+        name: "tick".into(),
+        ts: Timestamp(500),
+    };
+    let b = Event {
+        name: "tock".into(),
+        ts: Timestamp(500),
+    };
+    assert!(!a.eq_sec(&b));
+}
+
+#[test]
+fn projects_tuple_fields() {
+    let a = Labelled(1, "abc".into());
+    assert_eq!(a.0, 1);
+    assert!(a.eq_subset_ignoring(&Labelled(2, "xyz".into())));
+    assert!(!Labelled(1, "abc".into()).eq_subset_ignoring(&Labelled(1, "ab".into())));
+}
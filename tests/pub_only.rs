@@ -0,0 +1,59 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(pub_only, method = "eq_public")]
+pub struct Account {
+    pub id: u64,
+    pub name: String,
+    pub(crate) cache: u32,
+    secret: String,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(pub_only, ignore(name), method = "eq_id")]
+pub struct Profile {
+    pub id: u64,
+    pub name: String,
+    token: u32,
+}
+
+fn account() -> Account {
+    Account {
+        id: 1,
+        name: "a".into(),
+        cache: 0,
+        secret: "s".into(),
+    }
+}
+
+#[test]
+fn compares_public_fields_only() {
+    let a = account();
+    let b = Account {
+        cache: 5,
+        secret: "other".into(),
+        ..account()
+    };
+    assert!(a.eq_public(&b));
+    assert!(!a.eq_public(&Account {
+        name: "b".into(),
+        ..account()
+    }));
+    assert_eq!((a.cache, a.secret.as_str()), (0, "s"));
+}
+
+#[test]
+fn ignore_removes_public_fields_too() {
+    let a = Profile {
+        id: 1,
+        name: "a".into(),
+        token: 0,
+    };
+    let b = Profile {
+        id: 1,
+        name: "b".into(),
+        token: 1,
+    };
+    assert!(a.eq_id(&b));
+    assert_ne!(a.token, b.token);
+}
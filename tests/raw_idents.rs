@@ -0,0 +1,78 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(r#type), method = "eq_ignoring_type", diff = "diff_fields")]
+struct Token {
+    r#type: String,
+    r#match: u32,
+    name: String,
+}
+
+fn token() -> Token {
+    Token {
+        r#type: "keyword".into(),
+        r#match: 1,
+        name: "fn".into(),
+    }
+}
+
+#[test]
+fn ignores_raw_field() {
+    let a = token();
+    let mut b = a.clone();
+    b.r#type = "ident".into();
+    assert!(a.eq_ignoring_type(&b));
+}
+
+#[test]
+fn compares_other_raw_field() {
+    let a = token();
+    let mut b = a.clone();
+    b.r#match = 2;
+    assert!(!a.eq_ignoring_type(&b));
+    assert_eq!(a.diff_fields(&b), vec!["match"]);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(r#id), method = "eq_content")]
+struct Plain {
+    id: u64,
+    body: String,
+}
+
+#[test]
+fn raw_spelling_matches_plain_field() {
+    let a = Plain {
+        id: 1,
+        body: "x".into(),
+    };
+    let b = Plain {
+        id: 2,
+        body: "x".into(),
+    };
+    assert!(a.eq_content(&b));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(name), method = "eq_kind")]
+enum Item {
+    Keyword { r#type: u8, name: String },
+}
+
+#[test]
+fn enum_binds_raw_field() {
+    let a = Item::Keyword {
+        r#type: 1,
+        name: "a".into(),
+    };
+    let b = Item::Keyword {
+        r#type: 1,
+        name: "b".into(),
+    };
+    let c = Item::Keyword {
+        r#type: 2,
+        name: "a".into(),
+    };
+    assert!(a.eq_kind(&b));
+    assert!(!a.eq_kind(&c));
+}
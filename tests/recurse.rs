@@ -0,0 +1,65 @@
+use subset_eq::subset_eq;
+
+#[derive(Clone)]
+#[subset_eq(ignore(cached_at), method = "eq_ignoring_cache")]
+struct Leaf {
+    value: u32,
+    cached_at: i64,
+}
+
+#[derive(Clone)]
+#[subset_eq(
+    ignore(updated_at),
+    recurse(leaf = "eq_ignoring_cache"),
+    method = "eq_ignoring_inner"
+)]
+struct Meta {
+    author: String,
+    leaf: Leaf,
+    updated_at: i64,
+}
+
+#[derive(Clone)]
+#[subset_eq(recurse(meta = "eq_ignoring_inner"), ignore(ts), method = "eq_deep")]
+struct Doc {
+    id: u64,
+    meta: Meta,
+    ts: i64,
+}
+
+fn doc() -> Doc {
+    Doc {
+        id: 1,
+        meta: Meta {
+            author: "ann".into(),
+            leaf: Leaf {
+                value: 7,
+                cached_at: 0,
+            },
+            updated_at: 0,
+        },
+        ts: 0,
+    }
+}
+
+#[test]
+fn every_level_ignores_its_own_volatile_field() {
+    let a = doc();
+    let mut b = a.clone();
+    b.ts = 1;
+    b.meta.updated_at = 2;
+    b.meta.leaf.cached_at = 3;
+    assert!(a.eq_deep(&b));
+}
+
+#[test]
+fn nested_compared_fields_still_count() {
+    let a = doc();
+    let mut b = a.clone();
+    b.meta.leaf.value = 8;
+    assert!(!a.eq_deep(&b));
+    let mut c = a.clone();
+    c.meta.author = "bob".into();
+    assert!(!a.eq_deep(&c));
+    assert_eq!(c.id, a.id);
+}
@@ -0,0 +1,76 @@
+use subset_eq::subset_eq;
+
+struct ItemRef<'a> {
+    id: &'a u64,
+    name: &'a str,
+}
+
+#[subset_eq(ignore(big), ref_other = "ItemRef", method = "eq_meta")]
+struct Item {
+    id: u64,
+    name: String,
+    big: Vec<u8>,
+}
+
+#[test]
+fn compares_against_a_borrowed_view() {
+    let item = Item {
+        id: 1,
+        name: "widget".into(),
+        big: vec![0; 16],
+    };
+    let id = 1;
+    assert!(item.matches_ref(&ItemRef {
+        id: &id,
+        name: "widget",
+    }));
+    assert!(!item.matches_ref(&ItemRef {
+        id: &2,
+        name: "widget",
+    }));
+    assert!(!item.matches_ref(&ItemRef {
+        id: &id,
+        name: "gadget",
+    }));
+    assert_eq!(item.big.len(), 16);
+}
+
+#[test]
+fn combines_with_the_main_method() {
+    let a = Item {
+        id: 1,
+        name: "widget".into(),
+        big: vec![1],
+    };
+    let b = Item {
+        id: 1,
+        name: "widget".into(),
+        big: vec![2],
+    };
+    assert!(a.eq_meta(&b));
+}
+
+struct SlotRef<'a, T> {
+    key: &'a T,
+}
+
+// Only `Slot<T>` with `T: PartialEq` gets `matches_ref`, as with the main method.
+#[subset_eq(ignore(hits), ref_other = "SlotRef<T>")]
+struct Slot<T> {
+    key: T,
+    hits: u32,
+}
+
+#[test]
+fn compares_generic_structs_against_a_view() {
+    let slot = Slot {
+        key: "a".to_string(),
+        hits: 3,
+    };
+    let key = "a".to_string();
+    assert!(slot.matches_ref(&SlotRef { key: &key }));
+    assert!(!slot.matches_ref(&SlotRef {
+        key: &"b".to_string()
+    }));
+    assert_eq!(slot.hits, 3);
+}
@@ -0,0 +1,38 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(updated_at, cache_token), reflect, method = "eq_content")]
+struct Doc {
+    id: u64,
+    updated_at: i64,
+    body: String,
+    #[subset_skip]
+    cache_token: u32,
+}
+
+#[subset_eq(reflect)]
+struct Point(i32, i32);
+
+#[test]
+fn lists_both_field_sets_in_declaration_order() {
+    assert_eq!(Doc::SUBSET_EQ_COMPARED, ["id", "body"]);
+    assert_eq!(Doc::SUBSET_EQ_IGNORED, ["updated_at", "cache_token"]);
+}
+
+#[test]
+fn ignored_list_may_be_empty() {
+    assert_eq!(Point::SUBSET_EQ_COMPARED, ["0", "1"]);
+    assert!(Point::SUBSET_EQ_IGNORED.is_empty());
+    assert!(Point(1, 2).eq_subset_ignoring(&Point(1, 2)));
+}
+
+#[test]
+fn keeps_the_comparison() {
+    let doc = |updated_at, cache_token| Doc {
+        id: 1,
+        updated_at,
+        body: "b".into(),
+        cache_token,
+    };
+    assert!(doc(0, 1).eq_content(&doc(5, 2)));
+    assert_eq!(doc(3, 4).updated_at + i64::from(doc(3, 4).cache_token), 7);
+}
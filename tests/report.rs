@@ -0,0 +1,61 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(ts), report = "ItemDiff")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(1), report = "PairDiff", method = "eq_first")]
+struct Pair(u8, u8, u8);
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "foo".into(),
+        ts: 0,
+    }
+}
+
+#[test]
+fn report_flags_changed_fields() {
+    let a = item();
+    let mut b = a.clone();
+    b.name = "bar".into();
+    b.ts = 7;
+    let report = a.subset_diff(&b);
+    assert!(!report.id_changed);
+    assert!(report.name_changed);
+    assert!(!report.is_equal());
+}
+
+#[test]
+fn report_is_equal_when_only_ignored_fields_change() {
+    let a = item();
+    let mut b = a.clone();
+    b.ts = 7;
+    assert!(a.subset_diff(&b).is_equal());
+}
+
+#[test]
+fn report_debug_output_names_flags() {
+    let a = item();
+    let mut b = a.clone();
+    b.id = 2;
+    let rendered = format!("{:?}", a.subset_diff(&b));
+    assert_eq!(
+        rendered,
+        "ItemDiff { id_changed: true, name_changed: false }"
+    );
+}
+
+#[test]
+fn tuple_report_uses_indexed_flags() {
+    let report = Pair(1, 2, 3).subset_diff(&Pair(1, 9, 4));
+    assert!(!report.field_0_changed);
+    assert!(report.field_2_changed);
+    assert!(Pair(1, 2, 3).eq_first(&Pair(1, 9, 3)));
+}
@@ -0,0 +1,58 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), runtime_mask, method = "eq_masked")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+    cache_token: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(runtime_mask)]
+struct Point(i64, i64);
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "a".into(),
+        ts: 0,
+        cache_token: 7,
+    }
+}
+
+#[test]
+fn bits_follow_declaration_order() {
+    assert_eq!(Item::FIELD_ID, 1);
+    assert_eq!(Item::FIELD_NAME, 2);
+    assert_eq!(Item::FIELD_TS, 4);
+    assert_eq!(Item::FIELD_CACHE_TOKEN, 8);
+    assert_eq!(Point::FIELD_1, 2);
+}
+
+#[test]
+fn set_bits_skip_fields() {
+    let a = item();
+    let b = Item {
+        name: "b".into(),
+        ts: 5,
+        cache_token: 8,
+        ..item()
+    };
+    assert!(!a.eq_masked(&b, 0));
+    assert!(!a.eq_masked(&b, Item::FIELD_NAME));
+    assert!(a.eq_masked(&b, Item::FIELD_NAME | Item::FIELD_CACHE_TOKEN));
+    assert!(!a.eq_masked(&b, Item::FIELD_CACHE_TOKEN | Item::FIELD_ID));
+    assert!(a.eq_masked(&b, u64::MAX));
+    assert_ne!(a.ts, b.ts);
+}
+
+#[test]
+fn works_on_tuple_structs_with_the_default_name() {
+    let a = Point(1, 2);
+    let b = Point(1, 3);
+    assert!(!a.eq_subset_ignoring(&b, 0));
+    assert!(a.eq_subset_ignoring(&b, Point::FIELD_1));
+    assert_ne!(a, b);
+}
@@ -0,0 +1,67 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), score = "similarity")]
+struct Person {
+    first: String,
+    last: String,
+    city: String,
+    age: u32,
+    ts: i64,
+}
+
+fn person() -> Person {
+    Person {
+        first: "Ada".into(),
+        last: "Lovelace".into(),
+        city: "London".into(),
+        age: 36,
+        ts: 0,
+    }
+}
+
+#[test]
+fn identical_records_score_one() {
+    let a = person();
+    let mut b = a.clone();
+    b.ts = 5;
+    assert_eq!(a.similarity(&b), 1.0);
+}
+
+#[test]
+fn two_of_four_differences_score_half() {
+    let a = person();
+    let mut b = a.clone();
+    b.city = "Paris".into();
+    b.age = 37;
+    assert_eq!(a.similarity(&b), 0.5);
+}
+
+#[test]
+fn completely_different_records_score_zero() {
+    let a = person();
+    let b = Person {
+        first: "Alan".into(),
+        last: "Turing".into(),
+        city: "Wilmslow".into(),
+        age: 41,
+        ts: 0,
+    };
+    assert_eq!(a.similarity(&b), 0.0);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(id), score = "similarity")]
+struct Gated {
+    id: u64,
+    #[cfg(any())]
+    extra: String,
+}
+
+#[test]
+fn configured_out_fields_score_one() {
+    let a = Gated { id: 1 };
+    let b = Gated { id: 2 };
+    assert_eq!(a.similarity(&b), 1.0);
+    assert_ne!(a, b);
+}
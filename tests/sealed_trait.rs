@@ -0,0 +1,62 @@
+use subset_eq::subset_eq;
+
+mod cmp {
+    subset_eq_traits::sealed! {
+        /// Records comparable while ignoring their volatile fields.
+        pub trait SubsetCmp {
+            fn eq_meta(&self, other: &Self) -> bool;
+        }
+        for super::Item, super::Order
+    }
+}
+
+use cmp::SubsetCmp;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), sealed_trait = "crate::cmp::SubsetCmp", method = "eq_meta")]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(placed_at), sealed_trait = "cmp::SubsetCmp", method = "eq_meta")]
+struct Order {
+    item: u64,
+    quantity: u32,
+    placed_at: i64,
+}
+
+fn all_match<T: SubsetCmp>(pairs: &[(T, T)]) -> bool {
+    pairs.iter().all(|(a, b)| a.eq_meta(b))
+}
+
+#[test]
+fn generic_code_calls_the_sealed_method() {
+    let item = Item { id: 1, ts: 0 };
+    assert_eq!(item.ts, 0);
+    assert!(all_match(&[(item.clone(), Item { ts: 9, ..item })]));
+    assert!(!all_match(&[(
+        Item { id: 1, ts: 0 },
+        Item { id: 2, ts: 0 }
+    )]));
+
+    let order = Order {
+        item: 1,
+        quantity: 2,
+        placed_at: 0,
+    };
+    assert_eq!(order.placed_at, 0);
+    let later = Order {
+        placed_at: 5,
+        ..order.clone()
+    };
+    assert!(all_match(&[(order.clone(), later)]));
+    assert!(!all_match(&[(
+        order.clone(),
+        Order {
+            quantity: 3,
+            ..order
+        }
+    )]));
+}
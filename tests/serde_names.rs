@@ -0,0 +1,28 @@
+// Fields with `#[serde(rename = "...")]` need serde's derive to compile, so the renamed case is
+// covered by the unit tests in `src/lib.rs`; here fields without one fall back to the ident.
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), diff = "diff_fields", try_method = "check_eq", serde_names)]
+struct User {
+    user_name: String,
+    r#type: u8,
+    ts: i64,
+}
+
+#[test]
+fn falls_back_to_field_names() {
+    let a = User {
+        user_name: "a".into(),
+        r#type: 1,
+        ts: 0,
+    };
+    let b = User {
+        user_name: "b".into(),
+        r#type: 2,
+        ts: 1,
+    };
+    assert_eq!(a.diff_fields(&b), ["user_name", "type"]);
+    assert_eq!(a.check_eq(&b), Err("user_name"));
+    assert_ne!(a.ts, b.ts);
+}
@@ -0,0 +1,78 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(
+    skip_if_both("str::is_empty", fields = (nickname, bio)),
+    skip_if_both("Option::is_none", fields = (age)),
+    method = "eq_present"
+)]
+struct Profile {
+    id: u32,
+    nickname: String,
+    bio: String,
+    age: Option<u8>,
+}
+
+fn is_blank(s: &str) -> bool {
+    s.trim().is_empty()
+}
+
+#[derive(Debug)]
+#[subset_eq(skip_if_both("is_blank", fields = (0)))]
+struct Note(String);
+
+fn profile() -> Profile {
+    Profile {
+        id: 1,
+        nickname: String::new(),
+        bio: String::new(),
+        age: None,
+    }
+}
+
+#[test]
+fn both_empty_matches() {
+    let a = profile();
+    assert!(a.eq_present(&a.clone()));
+    let b = Profile {
+        nickname: "nick".into(),
+        ..profile()
+    };
+    assert!(b.eq_present(&b.clone()));
+}
+
+#[test]
+fn empty_against_non_empty_differs() {
+    let a = profile();
+    let b = Profile {
+        bio: "hello".into(),
+        ..profile()
+    };
+    assert!(!a.eq_present(&b));
+    assert!(!b.eq_present(&a));
+    assert!(!a.eq_present(&Profile {
+        age: Some(3),
+        ..profile()
+    }));
+}
+
+#[test]
+fn present_values_compare_with_eq() {
+    let a = Profile {
+        age: Some(3),
+        ..profile()
+    };
+    assert!(a.eq_present(&a.clone()));
+    assert!(!a.eq_present(&Profile {
+        age: Some(4),
+        ..profile()
+    }));
+    assert!(!a.eq_present(&Profile { id: 2, ..a.clone() }));
+}
+
+#[test]
+fn differing_values_are_skipped_when_both_satisfy_the_predicate() {
+    assert!(Note("  ".into()).eq_subset_ignoring(&Note("\t".into())));
+    assert!(!Note("  ".into()).eq_subset_ignoring(&Note("x".into())));
+    assert!(!Note("a ".into()).eq_subset_ignoring(&Note("a".into())));
+}
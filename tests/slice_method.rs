@@ -0,0 +1,41 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(ts), slice_method = "slices_eq")]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+fn items(ts: i64) -> Vec<Item> {
+    (1..=3)
+        .map(|id| Item {
+            id,
+            ts: ts + id as i64,
+        })
+        .collect()
+}
+
+#[test]
+fn equal_when_only_ignored_fields_differ() {
+    let a = items(0);
+    let b = items(100);
+    assert_ne!(a, b);
+    assert!(Item::slices_eq(&a, &b));
+    assert!(a[0].eq_subset_ignoring(&b[0]));
+}
+
+#[test]
+fn element_difference_is_detected() {
+    let a = items(0);
+    let mut b = items(0);
+    b[2].id = 9;
+    assert!(!Item::slices_eq(&a, &b));
+}
+
+#[test]
+fn differing_lengths_are_unequal() {
+    let a = items(0);
+    assert!(!Item::slices_eq(&a, &a[..2]));
+    assert!(Item::slices_eq(&[], &[]));
+}
@@ -0,0 +1,58 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(updated_at), strict, method = "eq_content")]
+struct Record {
+    id: u64,
+    updated_at: i64,
+}
+
+#[test]
+fn accepts_an_effective_exclusion() {
+    let a = Record {
+        id: 1,
+        updated_at: 0,
+    };
+    let b = Record {
+        id: 1,
+        updated_at: 5,
+    };
+    assert!(a.eq_content(&b));
+    assert_ne!(a.updated_at, b.updated_at);
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(updated_at))]
+struct Meta {
+    author: String,
+    updated_at: i64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(meta.updated_at), strict, method = "eq_content")]
+struct Outer {
+    id: u64,
+    meta: Meta,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(array_ignore(slots = [1]), strict, method = "eq_content")]
+struct Slots {
+    slots: [u8; 2],
+}
+
+#[test]
+fn accepts_a_nested_exclusion() {
+    let a = Outer {
+        id: 1,
+        meta: Meta {
+            author: "a".into(),
+            updated_at: 0,
+        },
+    };
+    let mut b = a.clone();
+    b.meta.updated_at = 5;
+    assert!(a.eq_content(&b));
+    b.meta.author = "b".into();
+    assert!(!a.eq_content(&b));
+    assert!(Slots { slots: [1, 2] }.eq_content(&Slots { slots: [1, 3] }));
+}
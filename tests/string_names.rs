@@ -0,0 +1,80 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore("updated_at", cache_token), method = "eq_mixed")]
+struct Mixed {
+    id: u64,
+    updated_at: i64,
+    cache_token: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore(updated_at, cache_token), method = "eq_paths")]
+struct Paths {
+    id: u64,
+    updated_at: i64,
+    cache_token: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore("type"), method = "eq_plain_keyword")]
+struct PlainKeyword {
+    r#type: u8,
+    name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(ignore("r#type"), method = "eq_raw_keyword")]
+struct RawKeyword {
+    r#type: u8,
+    name: String,
+}
+
+#[test]
+fn mixed_syntax_matches_paths() {
+    let cases = [(1, 0, 0), (1, 5, 0), (1, 0, 7), (2, 0, 0)];
+    for (id, updated_at, cache_token) in cases {
+        let mixed = Mixed {
+            id,
+            updated_at,
+            cache_token,
+        };
+        let paths = Paths {
+            id,
+            updated_at,
+            cache_token,
+        };
+        let mixed_base = Mixed {
+            id: 1,
+            updated_at: 0,
+            cache_token: 0,
+        };
+        let paths_base = Paths {
+            id: 1,
+            updated_at: 0,
+            cache_token: 0,
+        };
+        assert_eq!(mixed_base.eq_mixed(&mixed), paths_base.eq_paths(&paths));
+    }
+}
+
+#[test]
+fn keyword_names_with_and_without_prefix() {
+    let a = PlainKeyword {
+        r#type: 1,
+        name: "a".into(),
+    };
+    let mut b = a.clone();
+    b.r#type = 2;
+    assert!(a.eq_plain_keyword(&b));
+
+    let a = RawKeyword {
+        r#type: 1,
+        name: "a".into(),
+    };
+    let mut b = a.clone();
+    b.r#type = 2;
+    assert!(a.eq_raw_keyword(&b));
+    b.name = "b".into();
+    assert!(!a.eq_raw_keyword(&b));
+}
@@ -0,0 +1,60 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(method = "eq_stable")]
+struct Item {
+    id: u64,
+    name: String,
+    #[subset_skip]
+    updated_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(cache_token), method = "eq_core")]
+struct Combined {
+    id: u64,
+    /// Documented and skipped.
+    #[subset_skip]
+    updated_at: i64,
+    cache_token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(0))]
+struct Tuple(u8, #[subset_skip] u8, u8);
+
+#[test]
+fn marked_field_is_ignored() {
+    let a = Item {
+        id: 1,
+        name: "a".into(),
+        updated_at: 0,
+    };
+    let mut b = a.clone();
+    b.updated_at = 100;
+    assert_ne!(a, b);
+    assert!(a.eq_stable(&b));
+    b.name = "b".into();
+    assert!(!a.eq_stable(&b));
+}
+
+#[test]
+fn marker_combines_with_ignore_list() {
+    let a = Combined {
+        id: 1,
+        updated_at: 0,
+        cache_token: "x".into(),
+    };
+    let b = Combined {
+        id: 1,
+        updated_at: 1,
+        cache_token: "y".into(),
+    };
+    assert!(a.eq_core(&b));
+}
+
+#[test]
+fn marker_works_on_tuple_fields() {
+    assert!(Tuple(1, 2, 3).eq_subset_ignoring(&Tuple(9, 9, 3)));
+    assert!(!Tuple(1, 2, 3).eq_subset_ignoring(&Tuple(1, 2, 4)));
+}
@@ -0,0 +1,42 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[subset_eq(total(score), method = "eq_total")]
+struct Entry {
+    id: u32,
+    score: f64,
+    weight: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[subset_eq(total(1))]
+struct Pair(u8, f32);
+
+#[test]
+fn nan_scores_compare_equal() {
+    let a = Entry {
+        id: 1,
+        score: f64::NAN,
+        weight: 1.0,
+    };
+    assert_ne!(a, a);
+    assert!(a.eq_total(&a));
+}
+
+#[test]
+fn signed_zeros_and_other_values_differ() {
+    let a = Entry {
+        id: 1,
+        score: 0.0,
+        weight: 1.0,
+    };
+    assert!(!a.eq_total(&Entry { score: -0.0, ..a }));
+    assert!(!a.eq_total(&Entry { score: 0.5, ..a }));
+    assert!(!a.eq_total(&Entry { id: 2, ..a }));
+}
+
+#[test]
+fn tuple_fields() {
+    assert!(Pair(1, f32::NAN).eq_subset_ignoring(&Pair(1, f32::NAN)));
+    assert!(!Pair(1, f32::NAN).eq_subset_ignoring(&Pair(2, f32::NAN)));
+}
@@ -0,0 +1,47 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone)]
+#[subset_eq(ignore(ts), try_method = "check_eq")]
+struct Item {
+    id: u64,
+    name: String,
+    ts: i64,
+}
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "a".into(),
+        ts: 0,
+    }
+}
+
+fn validate(a: &Item, b: &Item) -> Result<u64, &'static str> {
+    a.check_eq(b)?;
+    Ok(a.id)
+}
+
+#[test]
+fn matching_values_are_ok() {
+    let a = item();
+    let b = Item { ts: 9, ..item() };
+    assert_eq!(a.check_eq(&b), Ok(()));
+    assert_eq!(validate(&a, &b), Ok(1));
+}
+
+#[test]
+fn reports_the_first_differing_field() {
+    let a = item();
+    let b = Item {
+        name: "b".into(),
+        ..item()
+    };
+    assert_eq!(a.check_eq(&b), Err("name"));
+    let c = Item {
+        id: 2,
+        name: "b".into(),
+        ts: 0,
+    };
+    assert_eq!(validate(&a, &c), Err("id"));
+    assert_eq!(a.ts, 0);
+}
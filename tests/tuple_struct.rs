@@ -0,0 +1,30 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(2), method = "eq_coords")]
+struct Point(i64, i64, String);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(only(1), method = "eq_second")]
+struct Pair(u8, u8);
+
+#[test]
+fn ignored_index_is_skipped() {
+    let a = Point(1, 2, "origin".into());
+    let b = Point(1, 2, "renamed".into());
+    assert_ne!(a, b);
+    assert!(a.eq_coords(&b));
+}
+
+#[test]
+fn compared_index_detects_change() {
+    let a = Point(1, 2, "p".into());
+    let b = Point(1, 3, "p".into());
+    assert!(!a.eq_coords(&b));
+}
+
+#[test]
+fn only_index_compares_single_field() {
+    assert!(Pair(1, 5).eq_second(&Pair(2, 5)));
+    assert!(!Pair(1, 5).eq_second(&Pair(1, 6)));
+}
@@ -0,0 +1,71 @@
+use subset_eq::subset_eq;
+
+fn approx_f64(a: &f64, b: &f64) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+fn exact_f64(a: &f64, b: &f64) -> bool {
+    a.to_bits() == b.to_bits()
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(type_with("f64" = "approx_f64"), method = "eq_fuzzy")]
+struct Sample {
+    label: String,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+#[derive(Debug, Clone)]
+#[subset_eq(
+    type_with(f64 = "approx_f64"),
+    with(exact = "exact_f64"),
+    method = "eq_fuzzy"
+)]
+struct Mixed {
+    loose: f64,
+    exact: f64,
+}
+
+fn sample() -> Sample {
+    Sample {
+        label: "s".into(),
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    }
+}
+
+#[test]
+fn every_field_of_the_type_uses_the_comparator() {
+    let a = sample();
+    let b = Sample {
+        x: 1.0 + 1e-9,
+        y: 2.0 - 1e-9,
+        z: 3.0 + 1e-9,
+        ..sample()
+    };
+    assert!(a.eq_fuzzy(&b));
+    assert!(!a.eq_fuzzy(&Sample { z: 3.1, ..sample() }));
+    assert!(!a.eq_fuzzy(&Sample {
+        label: "t".into(),
+        ..sample()
+    }));
+}
+
+#[test]
+fn field_rules_win_over_type_rules() {
+    let a = Mixed {
+        loose: 1.0,
+        exact: 1.0,
+    };
+    assert!(a.eq_fuzzy(&Mixed {
+        loose: 1.0 + 1e-9,
+        exact: 1.0,
+    }));
+    assert!(!a.eq_fuzzy(&Mixed {
+        loose: 1.0,
+        exact: 1.0 + 1e-9,
+    }));
+}
@@ -0,0 +1,6 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(allow_empty, score = "similarity")]
+struct Marker;
+
+fn main() {}
@@ -0,0 +1,25 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(array_ignore(samples = [0, 8]))]
+struct Signal {
+    samples: [f64; 8],
+}
+
+#[subset_eq(array_ignore(samples = [0]))]
+struct Unsized {
+    samples: Vec<f64>,
+}
+
+const N: usize = 4;
+
+#[subset_eq(array_ignore(samples = [0]))]
+struct ConstLen {
+    samples: [f64; N],
+}
+
+#[subset_eq(array_ignore(samples = [1, 1]))]
+struct Twice {
+    samples: [f64; 2],
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use subset_eq::subset_eq;
+
+#[repr(C)]
+#[subset_eq(bytewise)]
+struct Padded {
+    a: u8,
+    b: u32,
+}
+
+fn main() {
+    let _ = Padded { a: 0, b: 0 }.eq_subset_ignoring(&Padded { a: 0, b: 0 });
+}
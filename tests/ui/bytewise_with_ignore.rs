@@ -0,0 +1,15 @@
+use subset_eq::subset_eq;
+
+#[repr(C)]
+#[subset_eq(ignore(ts), bytewise)]
+struct Sample {
+    value: u32,
+    ts: u32,
+}
+
+#[subset_eq(bytewise)]
+struct NoRepr {
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(name), case_insensitive(name))]
+struct User {
+    id: u64,
+    name: String,
+}
+
+fn main() {}
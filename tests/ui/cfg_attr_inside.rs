@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(cfg_attr(test, ignore(ts)))]
+struct Item {
+    id: u32,
+    ts: u64,
+}
+
+fn main() {}
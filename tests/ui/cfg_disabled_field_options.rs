@@ -0,0 +1,34 @@
+// Fixtures build without `cfg(test)`, the opposite of the integration tests: options naming the
+// now-disabled `enabled_*` fields must still expand and type-check, so only the marker error shows
+// (type errors would still be reported after it).
+use subset_eq::subset_eq;
+
+fn close(a: &f64, b: &f64) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+#[subset_eq(
+    ignore(enabled_meta, disabled_meta),
+    with(enabled_score = "close", disabled_score = "close"),
+    compare_order(enabled_score, id),
+    method = "eq_meta"
+)]
+pub struct Gated {
+    id: u64,
+    #[cfg(test)]
+    enabled_meta: i64,
+    #[cfg(not(test))]
+    disabled_meta: i64,
+    #[cfg(test)]
+    enabled_score: f64,
+    #[cfg(not(test))]
+    disabled_score: f64,
+}
+
+pub fn compare(a: &Gated, b: &Gated) -> bool {
+    a.eq_meta(b)
+}
+
+compile_error!("expanded without errors");
+
+fn main() {}
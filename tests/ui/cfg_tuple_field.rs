@@ -0,0 +1,6 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(1))]
+struct Pair(#[cfg(any())] u8, u8, u8);
+
+fn main() {}
@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+use subset_eq::subset_eq;
+
+struct Widget;
+
+#[subset_eq(ignore(ts), check_bounds)]
+struct Catalog {
+    id: u64,
+    widgets: HashMap<String, Vec<Widget>>,
+    ts: i64,
+}
+
+fn main() {}
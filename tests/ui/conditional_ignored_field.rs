@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(cache), conditional(cache))]
+struct Item {
+    id: u64,
+    cache: u32,
+}
+
+fn main() {}
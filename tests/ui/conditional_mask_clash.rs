@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(runtime_mask, conditional(mask))]
+struct Filter {
+    id: u64,
+    mask: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), derive_partial_eq, derive_eq)]
+pub struct Item {
+    pub score: f64,
+    pub ts: i64,
+}
+
+fn main() {}
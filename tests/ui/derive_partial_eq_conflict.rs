@@ -0,0 +1,12 @@
+use subset_eq::subset_eq;
+
+// Derives listed below the attribute are visible to the macro and rejected with a clear message;
+// derives above it are expanded first, leaving rustc to report the conflicting impl.
+#[subset_eq(ignore(ts), derive_partial_eq)]
+#[derive(Debug, PartialEq)]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), derive_partial_eq, derive_partial_ord)]
+#[derive(PartialOrd)]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+fn main() {}
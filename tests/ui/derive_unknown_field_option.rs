@@ -0,0 +1,10 @@
+use subset_eq::SubsetEq;
+
+#[derive(SubsetEq)]
+struct Item {
+    id: u64,
+    #[subset(method = "eq_id")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! Every item below is documented except `Undocumented`, so it must be the only error: doc
+//! comments on the struct and its fields survive the macro, and the generated code documents
+//! every public item it adds.
+#![deny(missing_docs)]
+
+use subset_eq::subset_eq;
+
+/// A documented record.
+#[subset_eq(ignore(ts), diff = "diff_fields", report = "RecordDiff", method = "eq_meta")]
+pub struct Record {
+    /// Identifier.
+    pub id: u64,
+    /// Skipped cache, documented next to its marker.
+    #[subset_skip]
+    pub cache: u32,
+    /// Timestamp.
+    pub ts: i64,
+}
+
+pub struct Undocumented;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(updated_at, updated_at))]
+struct Item {
+    id: u64,
+    updated_at: i64,
+}
+
+fn main() {}
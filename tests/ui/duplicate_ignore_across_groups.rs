@@ -0,0 +1,10 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(updated_at), ignore(cache_token, updated_at))]
+struct Item {
+    id: u64,
+    updated_at: i64,
+    cache_token: u32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(method = "eq_full")]
+struct Empty {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(only(typo))]
+enum Event {
+    Created { id: u64 },
+    Renamed(u64, String),
+}
+
+fn main() {}
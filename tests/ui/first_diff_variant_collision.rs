@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(first_diff = "first_diff", diff_enum = "RowDiff")]
+struct Row {
+    foo_1: u8,
+    foo1: u8,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), first_diff = "first_diff")]
+struct Item {
+    id: u64,
+    ts: u64,
+}
+
+#[subset_eq(first_diff = "first_diff", diff_enum = "WrapperDiff")]
+struct Wrapper<T> {
+    value: T,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(group(header = (id, version), body = (version, payload)))]
+struct Record {
+    id: u32,
+    version: u16,
+    payload: Vec<u8>,
+}
+
+#[subset_eq(group(header = (id, versoin)))]
+struct Typo {
+    id: u32,
+    version: u16,
+}
+
+fn main() {}
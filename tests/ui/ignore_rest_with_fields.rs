@@ -0,0 +1,10 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(.., updated_at, keep = (id)))]
+struct Item {
+    id: u64,
+    name: String,
+    updated_at: i64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(..))]
+struct Item {
+    id: u64,
+    updated_at: i64,
+}
+
+fn main() {}
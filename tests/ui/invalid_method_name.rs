@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), method = "eq meta")]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(invert, method = "eq_meta_only")]
+struct Doc {
+    id: u64,
+    updated_at: i64,
+}
+
+fn main() {}
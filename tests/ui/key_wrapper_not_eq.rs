@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), key_wrapper = "PointKey")]
+struct Point {
+    x: f64,
+    ts: i64,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+mod shop {
+    use subset_eq::subset_eq;
+
+    // The wrapper is as private as the struct; only the methods default to `pub`.
+    #[subset_eq(ignore(ts), key_wrapper = "OrderKey")]
+    struct Order {
+        id: u64,
+        ts: i64,
+    }
+}
+
+fn keyed(_: shop::OrderKey) {}
+
+fn main() {}
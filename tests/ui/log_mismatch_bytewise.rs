@@ -0,0 +1,11 @@
+use subset_eq::subset_eq;
+
+#[repr(C)]
+#[subset_eq(log_mismatch, bytewise, method = "eq_bytes")]
+#[derive(Clone, Copy)]
+struct Pixel {
+    r: u8,
+    g: u8,
+}
+
+fn main() {}
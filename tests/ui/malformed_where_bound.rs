@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), where_bound = "T PartialEq")]
+struct Wrapper<T> {
+    value: T,
+    ts: i64,
+}
+
+fn main() {}
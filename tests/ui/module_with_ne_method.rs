@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), module = "item_cmp", ne_method = "ne_meta")]
+struct Item {
+    id: u32,
+    ts: u64,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![deny(unused_must_use)]
+
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(ts), attrs("must_use"), method = "eq_meta")]
+pub struct Item {
+    pub id: u64,
+    pub ts: i64,
+}
+
+pub fn drop_result(a: &Item, b: &Item) {
+    a.eq_meta(b);
+}
+
+fn main() {}
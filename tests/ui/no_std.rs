@@ -0,0 +1,38 @@
+// Compiles as a `#![no_std]` crate: any `std` path in the expansion would add an error here.
+// The fixture is built as a binary with unwinding panics, which adds the unwinding error.
+#![no_std]
+#![no_main]
+
+use subset_eq::subset_eq;
+
+#[subset_eq(
+    ignore(ts),
+    no_std,
+    method = "eq_meta",
+    diff = "diff_fields",
+    try_method = "check_eq",
+    field_eq_iter = "field_equality"
+)]
+pub struct Reading {
+    pub id: u32,
+    pub value: i16,
+    #[cfg(any())]
+    pub extra: u8,
+    pub ts: u64,
+}
+
+pub fn differing(a: &Reading, b: &Reading) -> usize {
+    let _ = a.check_eq(b);
+    let _ = a.field_equality(b).count();
+    if a.eq_meta(b) { 0 } else { a.diff_fields(b).1 }
+}
+
+// A type error is only reported once everything above resolved and type-checked.
+pub fn checked() -> u8 {
+    "type-checked without other errors"
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
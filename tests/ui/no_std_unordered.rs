@@ -0,0 +1,8 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(no_std, unordered(tags))]
+struct Tagged {
+    tags: [u8; 2],
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use subset_eq::subset_eq;
+
+#[derive(PartialEq)]
+pub struct Meta {
+    pub updated_at: u64,
+}
+
+#[subset_eq(ignore(meta.updated_at), only(id))]
+pub struct Item {
+    pub id: u64,
+    pub meta: Meta,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(preset = "created_at,updated_at", ignore(updated_at))]
+struct Record {
+    id: u64,
+    created_at: i64,
+    updated_at: i64,
+}
+
+fn main() {}
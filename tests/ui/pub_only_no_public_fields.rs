@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(pub_only)]
+pub struct Secret {
+    key: String,
+    salt: u64,
+}
+
+fn main() {}
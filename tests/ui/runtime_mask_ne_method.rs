@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(runtime_mask, ne_method = "ne_masked")]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+fn main() {}
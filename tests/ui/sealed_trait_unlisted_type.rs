@@ -0,0 +1,25 @@
+use subset_eq::subset_eq;
+
+mod cmp {
+    subset_eq_traits::sealed! {
+        pub trait SubsetCmp {
+            fn eq_meta(&self, other: &Self) -> bool;
+        }
+        for super::Item
+    }
+}
+
+#[subset_eq(ignore(ts), sealed_trait = "crate::cmp::SubsetCmp", method = "eq_meta")]
+struct Item {
+    id: u64,
+    ts: i64,
+}
+
+// `Order` is not listed in `sealed!`, so the trait cannot be implemented for it.
+#[subset_eq(ignore(placed_at), sealed_trait = "crate::cmp::SubsetCmp", method = "eq_meta")]
+struct Order {
+    item: u64,
+    placed_at: i64,
+}
+
+fn main() {}
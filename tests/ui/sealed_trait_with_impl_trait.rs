@@ -0,0 +1,12 @@
+use subset_eq::subset_eq;
+
+trait Compare {
+    fn compare(&self, other: &Self) -> bool;
+}
+
+#[subset_eq(impl_trait = "Compare", sealed_trait = "Compare", method = "compare")]
+struct Item {
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use subset_eq::subset_eq;
+
+struct Bar {
+    id: u64,
+}
+
+#[subset_eq(method = "eq_foo")]
+type Foo = Bar;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(ignore(b))]
+pub union Bits {
+    a: u32,
+    b: f32,
+}
+
+fn main() {}
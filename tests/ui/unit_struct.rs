@@ -0,0 +1,6 @@
+use subset_eq::subset_eq;
+
+#[subset_eq(method = "eq_marker")]
+pub struct Marker;
+
+fn main() {}
@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use subset_eq::subset_eq;
+
+#[derive(Clone)]
+#[subset_eq(unordered(tags, roles), ignore(ts), method = "eq_sets")]
+struct User {
+    name: String,
+    tags: Vec<u32>,
+    roles: HashSet<String>,
+    ts: i64,
+}
+
+fn user(tags: Vec<u32>) -> User {
+    User {
+        name: "a".into(),
+        tags,
+        roles: ["admin", "dev"].map(String::from).into(),
+        ts: 0,
+    }
+}
+
+#[test]
+fn element_order_does_not_matter() {
+    assert!(user(vec![1, 2, 3]).eq_sets(&user(vec![3, 2, 1])));
+    assert!(!user(vec![1, 2, 3]).eq_sets(&user(vec![1, 2, 4])));
+}
+
+#[test]
+fn multiplicity_matters() {
+    assert!(!user(vec![1, 1, 2]).eq_sets(&user(vec![1, 2, 2])));
+    assert!(!user(vec![1, 2]).eq_sets(&user(vec![1, 2, 2])));
+}
+
+#[test]
+fn other_fields_are_still_compared() {
+    let a = user(vec![1]);
+    let mut b = a.clone();
+    b.ts = 5;
+    assert!(a.eq_sets(&b));
+    b.roles.insert("ops".into());
+    assert!(!a.eq_sets(&b));
+    b.roles = a.roles.clone();
+    b.name = "b".into();
+    assert!(!a.eq_sets(&b));
+}
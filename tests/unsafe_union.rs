@@ -0,0 +1,32 @@
+use subset_eq::subset_eq;
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+#[subset_eq(unsafe_union(raw), method = "eq_raw", ne_method = "ne_raw")]
+union Word {
+    raw: u32,
+    bytes: [u8; 4],
+    float: f32,
+}
+
+#[test]
+fn compares_the_listed_field() {
+    let a = Word { raw: 7 };
+    let b = Word { raw: 7 };
+    let c = Word { raw: 8 };
+    assert!(a.eq_raw(&b));
+    assert!(!a.eq_raw(&c));
+    assert!(a.ne_raw(&c));
+}
+
+#[test]
+fn reads_the_listed_field_whichever_was_written() {
+    let a = Word {
+        bytes: 7u32.to_ne_bytes(),
+    };
+    assert!(a.eq_raw(&Word { raw: 7 }));
+    let f = Word { float: 1.0 };
+    assert!(f.eq_raw(&Word {
+        raw: 1.0f32.to_bits()
+    }));
+}
@@ -0,0 +1,62 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(variants(
+    eq_ignoring_meta(updated_at, cache_token),
+    eq_identity_only(name, updated_at, cache_token)
+))]
+struct Item {
+    id: u64,
+    name: String,
+    updated_at: i64,
+    cache_token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[subset_eq(ignore(ts), method = "eq_no_ts", variants(eq_no_label(label)))]
+struct Tagged {
+    label: String,
+    value: u32,
+    ts: i64,
+}
+
+fn item() -> Item {
+    Item {
+        id: 1,
+        name: "foo".into(),
+        updated_at: 10,
+        cache_token: "tok".into(),
+    }
+}
+
+#[test]
+fn each_variant_uses_its_own_ignore_set() {
+    let a = item();
+    let mut b = a.clone();
+    b.updated_at = 20;
+    assert!(a.eq_ignoring_meta(&b));
+    assert!(a.eq_identity_only(&b));
+
+    b.name = "bar".into();
+    assert!(!a.eq_ignoring_meta(&b));
+    assert!(a.eq_identity_only(&b));
+
+    b.id = 2;
+    assert!(!a.eq_identity_only(&b));
+}
+
+#[test]
+fn variants_combine_with_main_method() {
+    let a = Tagged {
+        label: "a".into(),
+        value: 1,
+        ts: 0,
+    };
+    let b = Tagged {
+        label: "b".into(),
+        value: 1,
+        ts: 0,
+    };
+    assert!(!a.eq_no_ts(&b));
+    assert!(a.eq_no_label(&b));
+}
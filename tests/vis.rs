@@ -0,0 +1,37 @@
+mod inner {
+    use subset_eq::subset_eq;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[subset_eq(ignore(ts), vis = "pub(crate)", method = "eq_meta")]
+    pub struct Crate {
+        pub id: u64,
+        pub ts: i64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[subset_eq(ignore(ts), vis = "", method = "eq_private")]
+    pub struct Private {
+        pub id: u64,
+        pub ts: i64,
+    }
+
+    pub fn private_eq(a: &Private, b: &Private) -> bool {
+        a.eq_private(b)
+    }
+}
+
+use inner::{Crate, Private};
+
+#[test]
+fn crate_visible_method_is_callable() {
+    let a = Crate { id: 1, ts: 0 };
+    let b = Crate { id: 1, ts: 5 };
+    assert!(a.eq_meta(&b));
+}
+
+#[test]
+fn private_method_is_usable_within_its_module() {
+    let a = Private { id: 1, ts: 0 };
+    let b = Private { id: 1, ts: 5 };
+    assert!(inner::private_eq(&a, &b));
+}
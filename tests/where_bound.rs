@@ -0,0 +1,61 @@
+use subset_eq::subset_eq;
+
+trait Keyed {
+    fn key(&self) -> u32;
+}
+
+#[derive(Debug)]
+struct User {
+    id: u32,
+    nickname: String,
+}
+
+impl Keyed for User {
+    fn key(&self) -> u32 {
+        self.id
+    }
+}
+
+fn same_key<T: Keyed>(a: &T, b: &T) -> bool {
+    a.key() == b.key()
+}
+
+// `with(...)` fields get no automatic bound, so the comparator's requirement is spelled out.
+#[derive(Debug)]
+#[subset_eq(
+    ignore(ts),
+    with(value = "same_key"),
+    where_bound = "T: Keyed",
+    method = "eq_key"
+)]
+struct Entry<T> {
+    value: T,
+    ts: i64,
+}
+
+fn user(id: u32, nickname: &str) -> User {
+    User {
+        id,
+        nickname: nickname.into(),
+    }
+}
+
+#[test]
+fn custom_bound_enables_comparator() {
+    let a = Entry {
+        value: user(1, "ada"),
+        ts: 0,
+    };
+    let b = Entry {
+        value: user(1, "countess"),
+        ts: 5,
+    };
+    let c = Entry {
+        value: user(2, "ada"),
+        ts: 0,
+    };
+    assert!(a.eq_key(&b));
+    assert!(!a.eq_key(&c));
+    assert_ne!(b.value.nickname, c.value.nickname);
+    assert_ne!(a.ts, b.ts);
+}
@@ -0,0 +1,48 @@
+use subset_eq::subset_eq;
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(wildcard_none(middle_name, suffix), method = "matches")]
+struct Person {
+    first: String,
+    middle_name: Option<String>,
+    last: String,
+    suffix: Option<String>,
+}
+
+fn full() -> Person {
+    Person {
+        first: "John".into(),
+        middle_name: Some("Ronald".into()),
+        last: "Tolkien".into(),
+        suffix: Some("Jr.".into()),
+    }
+}
+
+fn partial() -> Person {
+    Person {
+        first: "John".into(),
+        middle_name: None,
+        last: "Tolkien".into(),
+        suffix: None,
+    }
+}
+
+#[test]
+fn none_matches_either_side() {
+    assert!(full().matches(&partial()));
+    assert!(partial().matches(&full()));
+}
+
+#[test]
+fn some_values_must_agree() {
+    let mut other = full();
+    other.middle_name = Some("Reuel".into());
+    assert!(!full().matches(&other));
+}
+
+#[test]
+fn non_wildcard_fields_still_compared() {
+    let mut other = partial();
+    other.last = "Lewis".into();
+    assert!(!full().matches(&other));
+}
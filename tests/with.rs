@@ -0,0 +1,74 @@
+use subset_eq::subset_eq;
+
+fn approx_eq(a: &f64, b: &f64) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+fn unordered_eq(a: &[String], b: &[String]) -> bool {
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort();
+    b.sort();
+    a == b
+}
+
+mod cmp {
+    pub fn same_len(a: &str, b: &str) -> bool {
+        a.len() == b.len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(
+    ignore(updated_at),
+    with(score = "approx_eq", tags = "unordered_eq"),
+    method = "eq_fuzzy"
+)]
+struct Scored {
+    id: u64,
+    score: f64,
+    tags: Vec<String>,
+    updated_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[subset_eq(with(1 = "cmp::same_len"), method = "eq_shape")]
+struct Labelled(u8, String);
+
+fn scored() -> Scored {
+    Scored {
+        id: 1,
+        score: 0.3,
+        tags: vec!["a".into(), "b".into()],
+        updated_at: 0,
+    }
+}
+
+#[test]
+fn custom_comparators_are_used() {
+    let a = scored();
+    let mut b = a.clone();
+    b.score = 0.1 + 0.2;
+    b.tags.reverse();
+    b.updated_at = 5;
+    assert_ne!(a, b);
+    assert!(a.eq_fuzzy(&b));
+}
+
+#[test]
+fn plain_fields_still_use_partial_eq() {
+    let a = scored();
+    let mut b = a.clone();
+    b.id = 2;
+    assert!(!a.eq_fuzzy(&b));
+
+    let mut c = a.clone();
+    c.score = 0.5;
+    assert!(!a.eq_fuzzy(&c));
+}
+
+#[test]
+fn tuple_fields_accept_comparators() {
+    assert!(Labelled(1, "abc".into()).eq_shape(&Labelled(1, "xyz".into())));
+    assert!(!Labelled(1, "abc".into()).eq_shape(&Labelled(1, "xy".into())));
+}